@@ -0,0 +1,371 @@
+#!/usr/bin/env python3
+#
+# Copyright Soramitsu Co., Ltd. All Rights Reserved.
+# SPDX-License-Identifier: Apache-2.0
+#
+
+import asyncio
+import functools
+
+from grpc import aio
+
+from .iroha import IrohaCrypto, IrohaGrpc, IrohaRpcError, IrohaTimeoutError, _IrohaGrpcBase
+
+
+class AsyncIrohaStream(object):
+    """
+    Asynchronous iterator over a gRPC server stream, the asyncio counterpart of IrohaStream.
+    aclose() (or leaving the async context manager) and cancel() cancel the underlying call,
+    the iteration just stops then. Cancelling the task awaiting the next item cancels the call as well
+
+    Usage example:
+    async with net.send_blocks_stream_query(query) as blocks:
+        async for block in blocks:
+            await handle(block)
+    """
+
+    def __init__(self, call, items):
+        """
+        :param call: the grpc.aio call object of the stream, its cancel() is used if any
+        :param items: asynchronous iterator over the items produced from the call responses
+        """
+        self._call = call
+        self._items = items
+        self._cancelled = False
+        # set when cancel() has cancelled the call, the pending read fails with CancelledError then
+        self._call_cancelled = False
+
+    @property
+    def cancelled(self):
+        """Whether the stream was closed or cancelled by the client"""
+        return self._cancelled
+
+    def __aiter__(self):
+        return self
+
+    async def __anext__(self):
+        if self._cancelled:
+            raise StopAsyncIteration
+        try:
+            return await self._items.__anext__()
+        except IrohaRpcError:
+            if self._cancelled:
+                raise StopAsyncIteration
+            raise
+        except asyncio.CancelledError:
+            if self._call_cancelled and not self._task_cancelling():
+                # the call itself was cancelled by cancel() or close(), not the awaiting task
+                self._call_cancelled = False
+                raise StopAsyncIteration
+            self.cancel()
+            raise
+
+    @staticmethod
+    def _task_cancelling():
+        """
+        Whether cancellation of the current task has been requested, Python 3.11 or newer
+        is needed to tell it, so on older versions the awaiting task cancelled together with
+        the call by cancel() just stops the iteration
+        """
+        task = asyncio.current_task()
+        cancelling = getattr(task, 'cancelling', None)
+        return bool(cancelling()) if callable(cancelling) else False
+
+    def cancel(self):
+        """
+        Cancel the underlying call
+        :return: None
+        """
+        self._cancelled = True
+        cancel = getattr(self._call, 'cancel', None)
+        if callable(cancel):
+            self._call_cancelled = True
+            cancel()
+
+    async def aclose(self):
+        """
+        Cancel the underlying call and release the stream
+        :return: None
+        """
+        self.cancel()
+        await self._items.aclose()
+
+    async def __aenter__(self):
+        return self
+
+    async def __aexit__(self, exc_type, exc_value, traceback):
+        await self.aclose()
+        return False
+
+
+class IrohaGrpcAsync(_IrohaGrpcBase):
+    """
+    asyncio implementation of gRPC transport to Iroha, mirrors IrohaGrpc.
+    Has to be created and used inside a running event loop, requires grpcio 1.32 or newer
+    (pip install iroha[aio]). Accepts the same arguments as IrohaGrpc, a channel passed
+    to the constructor has to be grpc.aio.Channel.
+    Can be used as an async context manager closing the client on exit
+
+    Usage example:
+    async with IrohaGrpcAsync('127.0.0.1:50051') as net:
+        await net.send_tx(tx)
+        async for status in net.tx_status_stream(tx):
+            print(status)
+    """
+
+    _channels = aio
+
+    async def close(self):
+        """
+        Cancel the open streams and close the channel if it was created by the client,
+        pending calls are cancelled then. A channel passed to the constructor is left open
+        :return: None
+        """
+        for stream in list(self._streams):
+            stream.cancel()
+        if self._owns_channel:
+            await self._channel.close()
+
+    async def __aenter__(self):
+        return self
+
+    async def __aexit__(self, exc_type, exc_value, traceback):
+        await self.close()
+        return False
+
+    async def _unary(self, method, request, timeout, retry_policy=None, metadata=None,
+                     **error_context):
+        """
+        Make a unary call retrying it according to the retry policy, see IrohaGrpc._unary
+        """
+        policy = retry_policy if retry_policy else self._retry_policy
+        metadata = self._call_metadata(metadata)
+        attempt = 1
+        while True:
+            attempt_timeout = policy.attempt_timeout(timeout)
+            try:
+                with IrohaGrpc._rpc_errors(attempt_timeout, **error_context):
+                    return await method(request, timeout=attempt_timeout, metadata=metadata)
+            except IrohaRpcError as e:
+                delay = policy.next_delay(e, attempt)
+                if delay is None:
+                    raise
+            await asyncio.sleep(delay)
+            attempt += 1
+
+    async def send_tx(self, transaction, timeout=None, retry_policy=None, metadata=None):
+        """
+        Send a transaction to Iroha, see IrohaGrpc.send_tx
+        :return: None
+        """
+        if not timeout:
+            timeout = self._timeout
+        await self._unary(self._command_service_stub.Torii, transaction, timeout, retry_policy,
+                          metadata,
+                          may_be_in_flight=True,
+                          tx_hashes=[IrohaGrpc._hex_payload_hash(transaction)])
+
+    async def send_tx_bytes(self, data, timeout=None, retry_policy=None, metadata=None,
+                            verify_signatures=True):
+        """
+        Send an already signed and serialized transaction to Iroha, see IrohaGrpc.send_tx_bytes
+        :return: the decoded protobuf Transaction
+        """
+        transaction = IrohaGrpc._decode_signed_tx(data, verify_signatures)
+        await self.send_tx(transaction, timeout, retry_policy, metadata)
+        return transaction
+
+    async def send_txs(self, transactions, timeout=None, retry_policy=None, metadata=None):
+        """
+        Send a series of transactions to Iroha at once, see IrohaGrpc.send_txs
+        :return: None
+        """
+        if not timeout:
+            timeout = self._timeout
+        tx_list, tx_hashes = IrohaGrpc._tx_list(transactions)
+        await self._unary(self._command_service_stub.ListTorii, tx_list, timeout, retry_policy,
+                          metadata,
+                          may_be_in_flight=True, tx_hashes=tx_hashes)
+
+    async def send_tx_blocking(self, transaction, timeout=None, raise_on_rejection=False,
+                               metadata=None):
+        """
+        Send a transaction to Iroha and wait until it is committed or rejected,
+        see IrohaGrpc.send_tx_blocking
+        :return: a tuple with the final symbolic status description,
+        integral status code, and error code (will be 0 if no error occurred)
+        """
+        if not timeout:
+            timeout = self._timeout
+        with IrohaGrpc._overall_deadline(timeout) as deadline:
+            await self.send_tx(transaction, timeout, self._retry_policy.with_deadline(deadline),
+                               metadata)
+            return await self.wait_for_tx(transaction, IrohaGrpc._remaining(deadline),
+                                          raise_on_rejection, metadata)
+
+    async def wait_for_tx(self, transaction, timeout=None, raise_on_rejection=False,
+                          metadata=None):
+        """
+        Wait until a sent transaction is committed or rejected, see IrohaGrpc.wait_for_tx
+        :return: a tuple with the final symbolic status description,
+        integral status code, and error code (will be 0 if no error occurred)
+        """
+        with IrohaGrpc._overall_deadline(timeout) as deadline:
+            status = None
+            statuses = self.tx_status_stream(transaction, timeout, raise_on_rejection, metadata)
+            try:
+                async for status in statuses:
+                    if status[1] in IrohaGrpc.FINAL_STATUSES:
+                        return status
+            finally:
+                await statuses.aclose()
+            if status:
+                return status
+            return await self.tx_status(transaction, IrohaGrpc._remaining(deadline),
+                                        raise_on_rejection,
+                                        self._retry_policy.with_deadline(deadline), metadata)
+
+    async def send_txs_blocking(self, transactions, timeout=None, metadata=None):
+        """
+        Send independent transactions to Iroha at once and wait for their final statuses,
+        the statuses are awaited concurrently, see IrohaGrpc.send_txs_blocking
+        :return: collections.OrderedDict mapping hex hashes of the transactions in the original order
+        to the final status tuples or to None for the transactions which have not reached
+        a final status in time
+        """
+        if not timeout:
+            timeout = self._timeout
+        with IrohaGrpc._overall_deadline(timeout) as deadline:
+            await self.send_txs(transactions, timeout, self._retry_policy.with_deadline(deadline),
+                                metadata)
+
+            async def wait(transaction):
+                try:
+                    return await self.wait_for_tx(transaction, IrohaGrpc._remaining(deadline),
+                                                  metadata=metadata)
+                except IrohaTimeoutError:
+                    return None
+
+            statuses = await asyncio.gather(*[wait(transaction) for transaction in transactions])
+        return IrohaGrpc._tx_report(transactions, statuses)
+
+    async def send_query(self, query, timeout=None, raise_on_error=False, retry_policy=None,
+                         metadata=None):
+        """
+        Send a query to Iroha, see IrohaGrpc.send_query
+        :return: a protobuf response to the query
+        """
+        if not timeout:
+            timeout = self._timeout
+        response = await self._unary(self._query_service_stub.Find, query, timeout, retry_policy,
+                                     metadata,
+                                     query_hash=IrohaGrpc._hex_payload_hash(query))
+        return IrohaGrpc._checked_query_response(response, raise_on_error)
+
+    async def query_iter(self, iroha, private_key, name, page_size=100, counter=1, timeout=None,
+                         metadata=None, **kwargs):
+        """
+        Asynchronous generator of results of a paginated query,
+        the pages are requested lazily one by one, see IrohaGrpc.query_iter
+
+        Usage example:
+        async for asset in net.query_iter(iroha, private_key, 'GetAccountAssets',
+                                          account_id='alice@test'):
+            print(asset.asset_id, asset.balance)
+        """
+        cursor = None
+        while True:
+            query = IrohaGrpc._page_query(iroha, private_key, name, page_size, counter, cursor,
+                                          kwargs)
+            response = await self.send_query(query, timeout, raise_on_error=True,
+                                             metadata=metadata)
+            items, cursor = IrohaGrpc._page(name, response)
+            for item in items:
+                yield item
+            if not cursor:
+                return
+            counter += 1
+
+    async def get_transaction(self, iroha, private_key, tx_hash, counter=1, timeout=None,
+                              as_dict=False, metadata=None):
+        """
+        Fetch a committed transaction by its hash, see IrohaGrpc.get_transaction
+        :return: protobuf Transaction (or dict) or None if the transaction is unknown
+        or not accessible by the query creator
+        """
+        query = IrohaGrpc._transaction_query(iroha, private_key, tx_hash, counter)
+        response = await self.send_query(query, timeout, metadata=metadata)
+        return IrohaGrpc._found_transaction(response, as_dict)
+
+    def send_blocks_stream_query(self, query, timeout=None, metadata=None):
+        """
+        Send a query for blocks stream to Iroha, see IrohaGrpc.send_blocks_stream_query
+        :return: AsyncIrohaStream over a stream of blocks
+        """
+        if not timeout:
+            timeout = self._timeout
+        errors = functools.partial(IrohaGrpc._rpc_errors, timeout,
+                                   query_hash=IrohaGrpc._hex_payload_hash(query))
+        with errors():
+            response = self._query_service_stub.FetchCommits(
+                query, timeout=timeout, metadata=self._call_metadata(metadata))
+
+        async def blocks():
+            with errors():
+                async for block in response:
+                    yield block
+
+        return self._track(AsyncIrohaStream(response, blocks()))
+
+    async def tx_status(self, transaction, timeout=None, raise_on_rejection=False,
+                        retry_policy=None, metadata=None):
+        """
+        Request a status of a transaction, see IrohaGrpc.tx_status
+        :return: a tuple with the symbolic status description,
+        integral status code, and error code (will be 0 if no error occurred)
+        """
+        if not timeout:
+            timeout = self._timeout
+        request = IrohaGrpc._tx_status_request(IrohaCrypto.hash(transaction))
+        response = await self._unary(self._command_service_stub.Status, request, timeout,
+                                     retry_policy, metadata, may_be_in_flight=True,
+                                     tx_hashes=[request.tx_hash])
+        return IrohaGrpc._tx_status_tuple(response, raise_on_rejection, transaction)
+
+    def tx_status_stream(self, transaction, timeout=None, raise_on_rejection=False, metadata=None):
+        """
+        Stream transaction statuses asynchronously, see IrohaGrpc.tx_status_stream
+        :return: AsyncIrohaStream over a series of tuples with symbolic status description,
+        integral status code, and error code (will be 0 if no error occurred)
+        """
+        tx_hash = IrohaCrypto.hash(transaction)
+        return self._tx_hash_status_stream(tx_hash, timeout, raise_on_rejection, transaction,
+                                           metadata)
+
+    def tx_hash_status_stream(self, transaction_hash: "str or bytes", timeout=None,
+                              raise_on_rejection=False, metadata=None):
+        """
+        Stream transaction statuses asynchronously, see IrohaGrpc.tx_hash_status_stream
+        :return: AsyncIrohaStream over a series of tuples with symbolic status description,
+        integral status code, and error code (will be 0 if no error occurred)
+        """
+        return self._tx_hash_status_stream(transaction_hash, timeout, raise_on_rejection,
+                                           metadata=metadata)
+
+    def _tx_hash_status_stream(self, transaction_hash, timeout, raise_on_rejection,
+                               transaction=None, metadata=None):
+        if not timeout:
+            timeout = self._timeout
+        request = IrohaGrpc._tx_status_request(transaction_hash)
+        errors = functools.partial(IrohaGrpc._rpc_errors, timeout, may_be_in_flight=True,
+                                   tx_hashes=[request.tx_hash])
+        with errors():
+            response = self._command_service_stub.StatusStream(
+                request, timeout=timeout, metadata=self._call_metadata(metadata))
+
+        async def statuses():
+            with errors():
+                async for status in response:
+                    yield IrohaGrpc._tx_status_tuple(status, raise_on_rejection, transaction)
+
+        return self._track(AsyncIrohaStream(response, statuses()))
@@ -0,0 +1,122 @@
+#!/usr/bin/env python3
+#
+# Copyright Soramitsu Co., Ltd. All Rights Reserved.
+# SPDX-License-Identifier: Apache-2.0
+#
+
+import json
+import os
+
+from .explorer import block_height, block_transfers
+
+
+class DepositTracker(object):
+    """
+    Watches the ledger for incoming transfers to a set of accounts.
+    Every deposit is delivered to the callback once: the height of the last processed block
+    and the deposits already delivered from the block being processed are kept in a checkpoint file,
+    so the tracking can be resumed after a restart.
+    The checkpoint is saved after every delivered deposit and after every block with deposits,
+    the progress over blocks without deposits is saved once in checkpoint_interval blocks
+    and when run() returns, so at most that many blocks are read again after a crash
+    """
+
+    def __init__(self, explorer, accounts, callback, checkpoint_path=None,
+                 asset_ids=None, from_height=1, checkpoint_interval=100):
+        """
+        Create a deposit tracker
+        :param explorer: iroha.explorer.Explorer instance used to read blocks
+        :param accounts: ids of watched accounts
+        :param callback: callable accepting an iroha.explorer.Transfer tuple,
+        if it raises the deposit is not marked as delivered
+        :param checkpoint_path: optional path of a file to persist the progress in
+        :param asset_ids: optional ids of assets to watch, all assets are watched by default
+        :param from_height: height to start from when there is no checkpoint yet
+        :param checkpoint_interval: number of blocks without deposits processed between checkpoint saves
+        """
+        self._explorer = explorer
+        self._accounts = set(accounts)
+        self._asset_ids = set(asset_ids) if asset_ids else None
+        self._callback = callback
+        self._checkpoint_path = checkpoint_path
+        self._checkpoint_interval = checkpoint_interval
+        self.last_height = from_height - 1
+        self._delivered = set()
+        self._unsaved_blocks = 0
+        self._load_checkpoint()
+
+    def _load_checkpoint(self):
+        if not self._checkpoint_path or not os.path.exists(self._checkpoint_path):
+            return
+        with open(self._checkpoint_path, 'r') as checkpoint_file:
+            checkpoint = json.load(checkpoint_file)
+        self.last_height = checkpoint['height']
+        self._delivered = set(checkpoint['delivered'])
+
+    def _save_checkpoint(self):
+        self._unsaved_blocks = 0
+        if not self._checkpoint_path:
+            return
+        checkpoint = {'height': self.last_height, 'delivered': sorted(self._delivered)}
+        tmp_path = self._checkpoint_path + '.tmp'
+        with open(tmp_path, 'w') as checkpoint_file:
+            json.dump(checkpoint, checkpoint_file)
+            checkpoint_file.flush()
+            os.fsync(checkpoint_file.fileno())
+        os.replace(tmp_path, self._checkpoint_path)
+
+    def flush(self):
+        """
+        Save the checkpoint if blocks were processed since the last save
+        :return: None
+        """
+        if self._unsaved_blocks:
+            self._save_checkpoint()
+
+    @staticmethod
+    def _deposit_key(transfer):
+        return '{}:{}'.format(transfer.tx_hash, transfer.command_index)
+
+    def is_deposit(self, transfer):
+        """
+        :param transfer: iroha.explorer.Transfer tuple
+        :return: whether the transfer is an incoming transfer to one of watched accounts
+        """
+        if transfer.dest_account_id not in self._accounts:
+            return False
+        return self._asset_ids is None or transfer.asset_id in self._asset_ids
+
+    def process_block(self, block):
+        """
+        Deliver deposits of a block and move the checkpoint past it
+        :param block: protobuf Block, has to be the next one after the last processed
+        :return: None
+        """
+        assert block_height(block) == self.last_height + 1, \
+            "Blocks have to be processed in order without gaps"
+        for transfer in block_transfers(block):
+            key = self._deposit_key(transfer)
+            if not self.is_deposit(transfer) or key in self._delivered:
+                continue
+            self._callback(transfer)
+            self._delivered.add(key)
+            self._save_checkpoint()
+        had_deposits = bool(self._delivered)
+        self.last_height = block_height(block)
+        self._delivered = set()
+        self._unsaved_blocks += 1
+        if had_deposits or self._unsaved_blocks >= self._checkpoint_interval:
+            self._save_checkpoint()
+
+    def run(self, follow=True):
+        """
+        Process blocks starting after the checkpoint
+        :param follow: wait for new blocks when the top of the ledger is reached,
+        otherwise return
+        :return: None
+        """
+        try:
+            for block in self._explorer.blocks(from_height=self.last_height + 1, follow=follow):
+                self.process_block(block)
+        finally:
+            self.flush()
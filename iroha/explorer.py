@@ -0,0 +1,234 @@
+#!/usr/bin/env python3
+#
+# Copyright Soramitsu Co., Ltd. All Rights Reserved.
+# SPDX-License-Identifier: Apache-2.0
+#
+
+import binascii
+import itertools
+from collections import namedtuple
+
+from .iroha import Iroha, IrohaCrypto, BlocksQueryError, QueryError
+
+# Error code of GetBlock query which means that the requested height
+# is greater than the current ledger height
+INVALID_HEIGHT_ERROR_CODE = 3
+
+Transfer = namedtuple('Transfer', ['height', 'tx_hash', 'command_index',
+                                   'created_time', 'creator_account_id',
+                                   'src_account_id', 'dest_account_id', 'asset_id',
+                                   'amount', 'description'])
+AccountCreation = namedtuple('AccountCreation', ['height', 'tx_hash', 'created_time',
+                                                 'creator_account_id', 'account_id',
+                                                 'public_key'])
+
+
+def block_height(block):
+    """
+    :param block: protobuf Block
+    :return: height of the block in the ledger
+    """
+    return block.block_v1.payload.height
+
+
+def block_transactions(block):
+    """
+    Generator of committed transactions of a block
+    :param block: protobuf Block
+    :return: an iterable over tuples of block height, hex encoded transaction hash
+    and protobuf Transaction
+    """
+    height = block_height(block)
+    for transaction in block.block_v1.payload.transactions:
+        tx_hash = binascii.hexlify(IrohaCrypto.hash(transaction)).decode('utf-8')
+        yield height, tx_hash, transaction
+
+
+def block_commands(block, name=None):
+    """
+    Generator of commands of committed transactions of a block
+    :param block: protobuf Block
+    :param name: CamelCased name of command to filter by, all commands are yielded if omitted
+    :return: an iterable over tuples of block height, hex encoded transaction hash,
+    protobuf Transaction and the command itself, e.g. TransferAsset message
+    """
+    field_name = Iroha._camel_case_to_snake_case(name) if name else None
+    for height, tx_hash, transaction in block_transactions(block):
+        for command in transaction.payload.reduced_payload.commands:
+            command_name = command.WhichOneof('command')
+            if field_name and command_name != field_name:
+                continue
+            yield height, tx_hash, transaction, getattr(command, command_name)
+
+
+def block_transfers(block):
+    """
+    Generator of asset transfers of committed transactions of a block
+    :param block: protobuf Block
+    :return: an iterable over Transfer tuples
+    """
+    for height, tx_hash, transaction in block_transactions(block):
+        reduced_payload = transaction.payload.reduced_payload
+        for index, command in enumerate(reduced_payload.commands):
+            if command.WhichOneof('command') != 'transfer_asset':
+                continue
+            transfer = command.transfer_asset
+            yield Transfer(height, tx_hash, index, reduced_payload.created_time,
+                           reduced_payload.creator_account_id,
+                           transfer.src_account_id, transfer.dest_account_id,
+                           transfer.asset_id, transfer.amount, transfer.description)
+
+
+class Explorer(object):
+    """
+    Ready-made iterators over the ledger contents for lightweight block explorers.
+    Blocks are fetched with GetBlock queries and,
+    when following the ledger, with blocks stream query
+    """
+
+    def __init__(self, net, iroha, private_key):
+        """
+        Create an explorer
+        :param net: IrohaGrpc instance connected to a peer
+        :param iroha: Iroha instance with creator account set,
+        the account needs permission to read blocks
+        :param private_key: private key of the creator account to sign queries with
+        """
+        self._net = net
+        self._iroha = iroha
+        self._private_key = private_key
+        self._counter = itertools.count(1)
+
+    def get_block(self, height):
+        """
+        Fetch a block by its height
+        :param height: height of the block, starts from 1
+        :return: protobuf Block or None if the ledger is not that high yet
+        :raise: QueryError if the peer responded with an error
+        """
+        query = self._iroha.query('GetBlock', counter=next(self._counter), height=height)
+        IrohaCrypto.sign_query(query, self._private_key)
+        response = self._net.send_query(query)
+        if response.HasField('error_response'):
+            error = response.error_response
+            if error.error_code == INVALID_HEIGHT_ERROR_CODE:
+                return None
+            raise QueryError(response)
+        return response.block_response.block
+
+    def _stream(self):
+        query = self._iroha.blocks_query(counter=next(self._counter))
+        IrohaCrypto.sign_query(query, self._private_key)
+        # the stream is cancelled as soon as the caller stops iterating
+        with self._net.send_blocks_stream_query(query) as responses:
+            for response in responses:
+                if response.HasField('block_error_response'):
+                    raise BlocksQueryError(response)
+                yield response.block_response.block
+
+    def listen_for_blocks(self, from_height=None):
+        """
+        Generator of blocks committed to the ledger, never stops by itself
+        :param from_height: height of the first block to yield, blocks committed earlier
+        are fetched one by one before switching to the stream,
+        only the blocks committed after the call are yielded if omitted
+        :return: an iterable over protobuf Block messages
+        :raise: QueryError (BlocksQueryError for the stream) if the peer responded with an error
+        """
+        if from_height is None:
+            return self._stream()
+        return self.blocks(from_height, follow=True)
+
+    def blocks(self, from_height=1, to_height=None, follow=False):
+        """
+        Generator of blocks in the ascending order of heights
+        :param from_height: the first block height to yield
+        :param to_height: optional last block height to yield (inclusive)
+        :param follow: when the top of the ledger is reached, wait for the new blocks
+        instead of stopping
+        :return: an iterable over protobuf Block messages
+        :raise: QueryError (BlocksQueryError for the stream) if the peer responded with an error
+        """
+        height = from_height
+        while to_height is None or height <= to_height:
+            block = self.get_block(height)
+            if block is None:
+                break
+            yield block
+            height += 1
+        if not follow or (to_height is not None and height > to_height):
+            return
+        for block in self._stream():
+            # blocks committed before the stream has been opened are fetched one by one
+            while height < block_height(block):
+                if to_height is not None and height > to_height:
+                    return
+                missed = self.get_block(height)
+                if missed is None:
+                    break
+                yield missed
+                height += 1
+            if block_height(block) < height:
+                continue
+            if to_height is not None and block_height(block) > to_height:
+                return
+            yield block
+            height = block_height(block) + 1
+            if to_height is not None and height > to_height:
+                return
+
+    def transactions(self, from_height=1, to_height=None, follow=False):
+        """
+        Generator of committed transactions
+        :return: an iterable over tuples of block height, hex encoded transaction hash
+        and protobuf Transaction
+        See Explorer.blocks for the parameters description
+        """
+        for block in self.blocks(from_height, to_height, follow):
+            yield from block_transactions(block)
+
+    def commands(self, name=None, from_height=1, to_height=None, follow=False):
+        """
+        Generator of committed commands
+        :param name: CamelCased name of command to filter by, all commands are yielded if omitted
+        :return: an iterable over tuples of block height, hex encoded transaction hash,
+        protobuf Transaction and the command itself
+        See Explorer.blocks for the rest of parameters description
+        """
+        for block in self.blocks(from_height, to_height, follow):
+            yield from block_commands(block, name)
+
+    def transfers(self, asset_id=None, account_id=None,
+                  from_height=1, to_height=None, follow=False):
+        """
+        Generator of committed asset transfers
+        :param asset_id: only transfers of this asset are yielded if specified
+        :param account_id: only transfers from or to this account are yielded if specified
+        :return: an iterable over Transfer tuples
+        See Explorer.blocks for the rest of parameters description
+        """
+        for block in self.blocks(from_height, to_height, follow):
+            for transfer in block_transfers(block):
+                if asset_id and transfer.asset_id != asset_id:
+                    continue
+                if account_id and account_id not in (transfer.src_account_id,
+                                                     transfer.dest_account_id):
+                    continue
+                yield transfer
+
+    def account_creations(self, domain_id=None, from_height=1, to_height=None, follow=False):
+        """
+        Generator of account creation history
+        :param domain_id: only accounts of this domain are yielded if specified
+        :return: an iterable over AccountCreation tuples
+        See Explorer.blocks for the rest of parameters description
+        """
+        for height, tx_hash, transaction, command in self.commands(
+                'CreateAccount', from_height, to_height, follow):
+            if domain_id and command.domain_id != domain_id:
+                continue
+            reduced_payload = transaction.payload.reduced_payload
+            yield AccountCreation(height, tx_hash, reduced_payload.created_time,
+                                  reduced_payload.creator_account_id,
+                                  '{}@{}'.format(command.account_name, command.domain_id),
+                                  command.public_key)
@@ -6,21 +6,370 @@
 
 from . import ed25519 as ed25519_sha3
 import nacl.signing as ed25519_sha2
+import nacl.exceptions
+import asyncio
 import hashlib
 import binascii
+import collections
+import concurrent.futures
+import contextlib
+import copy
+import datetime
+import decimal
+import enum
+import functools
 import grpc
+import json
+import numbers
+from google.protobuf import json_format
+from google.protobuf.message import DecodeError
 import time
 import re
 import os
+import random
+import threading
+import weakref
 
 from . import commands_pb2
 from . import endpoint_pb2
 from . import endpoint_pb2_grpc
 from . import primitive_pb2
+from . import qry_responses_pb2
 from . import queries_pb2
 from . import transaction_pb2
 
 
+class ErrorKind(enum.Enum):
+    """
+    Stable machine-readable kinds of errors raised by the library
+    """
+    OTHER = 'other'
+    CONFIG = 'config'
+    SERIALIZATION = 'serialization'
+    RPC = 'rpc'
+    CONNECTION = 'connection'
+    BAD_REQUEST = 'bad_request'
+    UNAUTHORIZED = 'unauthorized'
+    NOT_FOUND = 'not_found'
+    TOO_MANY_REQUESTS = 'too_many_requests'
+    PEER_UNAVAILABLE = 'peer_unavailable'
+    TIMEOUT = 'timeout'
+    QUERY = 'query'
+    TRANSACTION_REJECTED = 'transaction_rejected'
+
+
+class IrohaError(Exception):
+    """
+    Base class of all errors raised by the library.
+    Every error has .kind - ErrorKind member, and .data - dict with structured error details
+    """
+    kind = ErrorKind.OTHER
+
+    def __init__(self, *args, data=None):
+        super().__init__(*args)
+        self.data = dict(data) if data else {}
+
+
+class ConfigError(IrohaError, ValueError):
+    """
+    Invalid configuration was passed to the library
+    """
+    kind = ErrorKind.CONFIG
+
+
+class SerializationError(IrohaError, ValueError):
+    """
+    Command or query arguments do not match the schema
+    """
+    kind = ErrorKind.SERIALIZATION
+
+    @staticmethod
+    def from_error(error, message, data=None):
+        """
+        Wrap an error raised while filling a protobuf message,
+        the result is an instance of the original error type as well,
+        so the code catching AttributeError or TypeError keeps working
+        :param error: AttributeError, TypeError or ValueError
+        :param message: error message
+        :param data: structured error details
+        :return: UnknownFieldError, ArgumentTypeError or SerializationError
+        """
+        if isinstance(error, AttributeError):
+            return UnknownFieldError(message, data=data)
+        if isinstance(error, TypeError):
+            return ArgumentTypeError(message, data=data)
+        return SerializationError(message, data=data)
+
+
+class UnknownFieldError(SerializationError, AttributeError):
+    """
+    Unknown command, query or argument name
+    """
+
+
+class ArgumentTypeError(SerializationError, TypeError):
+    """
+    Command, query or timestamp argument has a wrong type
+    """
+
+
+class IrohaRpcError(IrohaError, grpc.RpcError):
+    """
+    gRPC call to Iroha failed.
+    Being a grpc.RpcError it provides .code() and .details() of the original error.
+    Hex hashes of the transactions or the query the call was about
+    are available as .tx_hashes and .query_hash
+    """
+    kind = ErrorKind.RPC
+
+    def __init__(self, rpc_error, tx_hashes=(), query_hash=None):
+        """
+        :param rpc_error: the original grpc.RpcError, available as .rpc_error
+        :param tx_hashes: hex hashes of the transactions sent or requested by the call
+        :param query_hash: hex hash of the query sent by the call
+        """
+        self.rpc_error = rpc_error
+        self.tx_hashes = list(tx_hashes)
+        self.query_hash = query_hash
+        message = '{}: {}'.format(self.code(), self.details())
+        data = {'grpc_code': self.code().name, 'grpc_details': self.details()}
+        if self.tx_hashes:
+            message += ' (transactions {})'.format(', '.join(self.tx_hashes))
+            data['tx_hashes'] = self.tx_hashes
+        if query_hash:
+            message += ' (query {})'.format(query_hash)
+            data['query_hash'] = query_hash
+        super().__init__(message, data=data)
+
+    @property
+    def tx_hash(self):
+        """
+        :return: hex hash of the transaction if the call was about a single one, otherwise None
+        """
+        return self.tx_hashes[0] if len(self.tx_hashes) == 1 else None
+
+    def code(self):
+        """
+        :return: grpc.StatusCode of the failed call
+        """
+        code = getattr(self.rpc_error, 'code', None)
+        return code() if callable(code) else grpc.StatusCode.UNKNOWN
+
+    def details(self):
+        """
+        :return: error details reported by gRPC
+        """
+        details = getattr(self.rpc_error, 'details', None)
+        return details() if callable(details) else str(self.rpc_error)
+
+
+class IrohaConnectionError(IrohaRpcError, ConnectionError):
+    """
+    Connection to Iroha peer failed
+    """
+    kind = ErrorKind.CONNECTION
+
+
+class BadRequest(IrohaRpcError):
+    """
+    Iroha peer refused a malformed request (INVALID_ARGUMENT)
+    """
+    kind = ErrorKind.BAD_REQUEST
+
+
+class Unauthorized(IrohaRpcError):
+    """
+    Iroha peer or a gateway in front of it refused to authorize the request
+    (UNAUTHENTICATED or PERMISSION_DENIED)
+    """
+    kind = ErrorKind.UNAUTHORIZED
+
+
+class NotFound(IrohaRpcError):
+    """
+    Requested method or entity was not found (NOT_FOUND or UNIMPLEMENTED)
+    """
+    kind = ErrorKind.NOT_FOUND
+
+
+class TooManyRequests(IrohaRpcError):
+    """
+    Iroha peer or a gateway in front of it is rate limiting the client (RESOURCE_EXHAUSTED)
+    """
+    kind = ErrorKind.TOO_MANY_REQUESTS
+
+
+class PeerUnavailable(IrohaConnectionError):
+    """
+    Iroha peer is not reachable or is unable to serve the request at the moment (UNAVAILABLE)
+    """
+    kind = ErrorKind.PEER_UNAVAILABLE
+
+
+class IrohaTimeoutError(IrohaRpcError, TimeoutError):
+    """
+    gRPC call to Iroha has not finished in time (DEADLINE_EXCEEDED).
+    When raised by a blocking call made of several requests, e.g. send_tx_blocking,
+    .deadline and .elapsed describe the whole call rather than the request which timed out
+    """
+    kind = ErrorKind.TIMEOUT
+
+    def __init__(self, rpc_error, deadline=None, elapsed=None, may_be_in_flight=False,
+                 tx_hashes=(), query_hash=None):
+        """
+        :param rpc_error: the original grpc.RpcError
+        :param deadline: configured timeout of the call in seconds, None if there was none
+        :param elapsed: seconds passed since the call was started
+        :param may_be_in_flight: whether the transaction the call was about could still be
+        accepted by the peer, so it is not safe to retry submission with a new transaction
+        :param tx_hashes: hex hashes of the transactions sent or requested by the call
+        :param query_hash: hex hash of the query sent by the call
+        """
+        super().__init__(rpc_error, tx_hashes, query_hash)
+        self.deadline = deadline
+        self.elapsed = elapsed
+        self.may_be_in_flight = may_be_in_flight
+        self.data.update(deadline=deadline, elapsed=elapsed, may_be_in_flight=may_be_in_flight)
+
+
+# exception classes for grpc status codes, IrohaRpcError is used for the rest
+_RPC_ERRORS = {
+    grpc.StatusCode.INVALID_ARGUMENT: BadRequest,
+    grpc.StatusCode.UNAUTHENTICATED: Unauthorized,
+    grpc.StatusCode.PERMISSION_DENIED: Unauthorized,
+    grpc.StatusCode.NOT_FOUND: NotFound,
+    grpc.StatusCode.UNIMPLEMENTED: NotFound,
+    grpc.StatusCode.RESOURCE_EXHAUSTED: TooManyRequests,
+    grpc.StatusCode.UNAVAILABLE: PeerUnavailable,
+}
+
+
+class QueryError(IrohaError):
+    """
+    Iroha responded to a query with ErrorResponse
+    """
+    kind = ErrorKind.QUERY
+
+    def __init__(self, response):
+        """
+        :param response: protobuf QueryResponse containing error_response
+        """
+        error = response.error_response
+        self.response = response
+        self.query_hash = response.query_hash
+        self.reason = qry_responses_pb2.ErrorResponse.Reason.Name(error.reason)
+        self.error_code = error.error_code
+        self.message = error.message
+        super().__init__('Query {} failed with {} (error code {}): {}'.format(
+            self.query_hash, self.reason, self.error_code, self.message),
+            data={'query_hash': self.query_hash, 'reason': self.reason,
+                  'error_code': self.error_code, 'message': self.message})
+
+
+class BlocksQueryError(QueryError):
+    """
+    Iroha responded to a blocks stream query with BlockErrorResponse,
+    the response carries no query hash, reason or error code
+    """
+
+    def __init__(self, response):
+        """
+        :param response: protobuf BlockQueryResponse containing block_error_response
+        """
+        self.response = response
+        self.query_hash = None
+        self.reason = None
+        self.error_code = None
+        self.message = response.block_error_response.message
+        IrohaError.__init__(self, 'Blocks stream failed: {}'.format(self.message),
+                            data={'message': self.message})
+
+
+class TransactionRejected(IrohaError):
+    """
+    Iroha reported a transaction as failed or rejected.
+    Use TransactionRejected.from_response to get the subclass matching the status
+    """
+    kind = ErrorKind.TRANSACTION_REJECTED
+
+    def __init__(self, response, message=None, data=None):
+        """
+        :param response: protobuf ToriiResponse with the failure status
+        :param message: error message, generated from the status if omitted
+        :param data: additional structured details
+        """
+        self.response = response
+        self.tx_hash = response.tx_hash
+        self.status_name = endpoint_pb2.TxStatus.Name(response.tx_status)
+        self.status_code = response.tx_status
+        self.error_code = response.error_code
+        if message is None:
+            message = 'Transaction {} status is {}, error code {}'.format(
+                self.tx_hash, self.status_name, self.error_code)
+        error_data = {'tx_hash': self.tx_hash, 'status': self.status_name,
+                      'error_code': self.error_code}
+        error_data.update(data or {})
+        super().__init__(message, data=error_data)
+
+    @staticmethod
+    def from_response(response, transaction=None):
+        """
+        Create an exception of the class corresponding to the failure status
+        :param response: protobuf ToriiResponse with the failure status
+        :param transaction: optional protobuf Transaction to look the failed command up in
+        :return: TransactionRejected or its subclass instance
+        """
+        if response.tx_status == endpoint_pb2.STATELESS_VALIDATION_FAILED:
+            return StatelessValidationFailed(response)
+        if response.tx_status == endpoint_pb2.STATEFUL_VALIDATION_FAILED:
+            return StatefulValidationFailed(response, transaction)
+        if response.tx_status == endpoint_pb2.MST_EXPIRED:
+            return MstExpired(response)
+        return TransactionRejected(response)
+
+
+class StatelessValidationFailed(TransactionRejected):
+    """
+    Transaction is malformed: bad signatures, wrong creation time, invalid command fields, etc.
+    The reason reported by the peer is available as .reason
+    """
+
+    def __init__(self, response):
+        self.reason = response.err_or_cmd_name
+        super().__init__(response, 'Transaction {} failed stateless validation: {}'.format(
+            response.tx_hash, self.reason), data={'reason': self.reason})
+
+
+class StatefulValidationFailed(TransactionRejected):
+    """
+    A command of the transaction could not be executed against the ledger state.
+    .command_name and .command_index identify the failed command,
+    .error_code is the command specific error code,
+    .command is the failed command itself if the transaction was known
+    """
+
+    def __init__(self, response, transaction=None):
+        self.command_name = response.err_or_cmd_name
+        self.command_index = response.failed_cmd_index
+        self.command = None
+        if transaction is not None:
+            commands = transaction.payload.reduced_payload.commands
+            if self.command_index < len(commands):
+                self.command = commands[self.command_index]
+        super().__init__(response, 'Transaction {} failed stateful validation: '
+                                   'command {} at index {} returned error code {}'.format(
+                                       response.tx_hash, self.command_name,
+                                       self.command_index, response.error_code),
+                         data={'command_name': self.command_name,
+                               'command_index': self.command_index})
+
+
+class MstExpired(TransactionRejected):
+    """
+    Multisignature transaction has not collected enough signatures in time
+    """
+
+
 class IrohaCrypto(object):
     """
     Collection of general crypto-related functions
@@ -178,8 +527,25 @@ class Iroha(object):
     Collection of factory methods for transactions and queries creation
     """
 
-    def __init__(self, creator_account=None):
+    NAIVE_DATETIME_POLICIES = ('local', 'utc', 'error')
+
+    # amounts are unsigned 256-bit integers scaled by up to 255 decimal places
+    AMOUNT_MAX_PRECISION = 255
+    AMOUNT_MAX_MANTISSA = 2 ** 256 - 1
+
+    def __init__(self, creator_account=None, naive_datetime='local'):
+        """
+        :param creator_account: default id of transactions and queries creator account
+        :param naive_datetime: how datetime.datetime timestamps without tzinfo are treated:
+        'local' - as local time (the same as datetime.timestamp() does),
+        'utc' - as UTC time, 'error' - SerializationError is raised
+        :raise: ConfigError if the policy is unknown
+        """
+        if naive_datetime not in self.NAIVE_DATETIME_POLICIES:
+            raise ConfigError('Unknown naive datetime policy: {}'.format(naive_datetime),
+                              data={'naive_datetime': naive_datetime})
         self.creator_account = creator_account
+        self.naive_datetime = naive_datetime
 
     @staticmethod
     def _camel_case_to_snake_case(camel_case_string):
@@ -192,6 +558,32 @@ class Iroha(object):
         """Current timestamp in milliseconds"""
         return int(round(time.time() * 1000))
 
+    def _timestamp(self, value):
+        """
+        Convert a timestamp argument to milliseconds
+        :param value: timestamp in milliseconds or datetime.datetime
+        :return: timestamp in milliseconds
+        :raise: ArgumentTypeError if the value is neither an integer nor a datetime,
+        SerializationError if it cannot be converted or datetime without tzinfo is passed
+        and naive_datetime policy is 'error'
+        """
+        if not isinstance(value, datetime.datetime):
+            if isinstance(value, bool) or not isinstance(value, numbers.Integral):
+                raise ArgumentTypeError('Timestamp should be milliseconds or datetime, got {!r}'
+                                        .format(value), data={'timestamp': repr(value)})
+            return int(value)
+        if value.tzinfo is None:
+            if self.naive_datetime == 'error':
+                raise SerializationError('Timezone-aware datetime is required, got {}'.format(value),
+                                         data={'timestamp': value.isoformat()})
+            if self.naive_datetime == 'utc':
+                value = value.replace(tzinfo=datetime.timezone.utc)
+        try:
+            return int(round(value.timestamp() * 1000))
+        except (OverflowError, OSError, ValueError) as e:
+            raise SerializationError('Cannot convert {} to a timestamp: {}'.format(value, e),
+                                     data={'timestamp': value.isoformat()}) from e
+
     def transaction(self, commands, quorum=1,
                     creator_account=None, created_time=None):
         """
@@ -199,13 +591,14 @@ class Iroha(object):
         :param commands: list of commands generated via command factory method
         :param quorum: required number of signatures, 1 is default
         :param creator_account: id of transaction creator account
-        :param created_time: transaction creation timestamp in milliseconds
+        :param created_time: transaction creation timestamp in milliseconds or datetime.datetime
         :return: a proto transaction
         """
         assert creator_account or self.creator_account, \
             "No account name specified as transaction creator id"
         if not created_time:
             created_time = self.now()
+        created_time = self._timestamp(created_time)
         if not creator_account:
             creator_account = self.creator_account
         tx = transaction_pb2.Transaction()
@@ -217,58 +610,100 @@ class Iroha(object):
         core_payload.commands.extend(commands)
         return tx
 
+    @staticmethod
+    def _decimal_to_amount(value):
+        """
+        Convert decimal.Decimal to Iroha amount string without loss of precision
+        :param value: decimal.Decimal
+        :return: amount string in positional notation
+        :raise: ValueError if the value cannot be represented as an amount
+        """
+        if not value.is_finite() or value.is_signed():
+            raise ValueError('Amount has to be a finite non-negative number, got {}'.format(value))
+        _, digits, exponent = value.as_tuple()
+        if -exponent > Iroha.AMOUNT_MAX_PRECISION:
+            raise ValueError('Amount {} has {} digits after the point, at most {} are allowed'.format(
+                value, -exponent, Iroha.AMOUNT_MAX_PRECISION))
+        # the digits count is checked first not to build huge integers for large exponents
+        max_digits = len(str(Iroha.AMOUNT_MAX_MANTISSA))
+        if len(digits) + max(exponent, 0) > max_digits or \
+                int(''.join(map(str, digits))) * 10 ** max(exponent, 0) > Iroha.AMOUNT_MAX_MANTISSA:
+            raise ValueError('Amount {} does not fit into 256 bits'.format(value))
+        return format(value, 'f')
+
     @staticmethod
     def command(name, **kwargs):
         """
         Creates a protobuf command to be inserted into a transaction
         :param name: CamelCased name of command
-        :param kwargs: command arguments as they defined in schema
+        :param kwargs: command arguments as they defined in schema,
+        amounts can be passed as decimal.Decimal
         :return: a proto command
+        :raise: SerializationError if the command name or arguments do not match the schema
 
         Usage example:
         cmd = Iroha.command('CreateDomain', domain_id='test', default_role='user')
+        cmd = Iroha.command('AddAssetQuantity', asset_id='coin#test', amount=Decimal('13.37'))
         """
         command_wrapper = commands_pb2.Command()
         field_name = Iroha._camel_case_to_snake_case(name)
-        internal_command = getattr(command_wrapper, field_name)
-        for key, value in kwargs.items():
-            if 'permissions' == key:
-                permissions_attr = getattr(internal_command, key)
-                permissions_attr.extend(value)
-                continue
-            if 'peer' == key:
-                peer_attr = getattr(internal_command, key)
-                peer_attr.CopyFrom(value)
-                continue
-            setattr(internal_command, key, value)
+        try:
+            internal_command = getattr(command_wrapper, field_name)
+            for key, value in kwargs.items():
+                if 'permissions' == key:
+                    permissions_attr = getattr(internal_command, key)
+                    permissions_attr.extend(value)
+                    continue
+                if 'peer' == key:
+                    peer_attr = getattr(internal_command, key)
+                    peer_attr.CopyFrom(value)
+                    continue
+                if isinstance(value, decimal.Decimal):
+                    value = Iroha._decimal_to_amount(value)
+                setattr(internal_command, key, value)
+        except (AttributeError, TypeError, ValueError) as e:
+            raise SerializationError.from_error(e, 'Cannot create {} command: {}'.format(name, e),
+                                                data={'command': name}) from e
         return command_wrapper
 
     def query(self, name, counter=1, creator_account=None,
               created_time=None, page_size=None, first_tx_hash=None,
-              **kwargs):
+              ordering=None, **kwargs):
         """
         Creates a protobuf query with specified set of entities
         :param name: CamelCased name of query to be executed
         :param counter: query counter, should be incremented for each new query
         :param creator_account: account id of query creator
-        :param created_time: query creation timestamp in milliseconds
+        :param created_time: query creation timestamp in milliseconds or datetime.datetime
         :param page_size: a non-zero positive number, size of result rowset for queries with pagination
         :param first_tx_hash: optional hash of a transaction that will be the beginning of the next page
+        :param ordering: optional server side ordering of transactions for queries with pagination,
+        a list of (field, direction) tuples where the field is 'kCreatedTime' or 'kPosition'
+        and the direction is 'kAscending' or 'kDescending', earlier tuples take precedence
         :param kwargs: query arguments as they defined in schema
         :return: a proto query
+        :raise: SerializationError if the query name or arguments do not match the schema
+
+        Usage example:
+        query = iroha.query('GetAccountTransactions', account_id='alice@test', page_size=10,
+                            ordering=[('kCreatedTime', 'kDescending')])
         """
         assert creator_account or self.creator_account, \
             "No account name specified as query creator id"
         pagination_meta = None
         if not created_time:
             created_time = self.now()
+        created_time = self._timestamp(created_time)
         if not creator_account:
             creator_account = self.creator_account
-        if page_size or first_tx_hash:
+        if page_size or first_tx_hash or ordering:
             pagination_meta = queries_pb2.TxPaginationMeta()
-            pagination_meta.page_size = page_size
+            if page_size:
+                pagination_meta.page_size = page_size
             if first_tx_hash:
                 pagination_meta.first_tx_hash = first_tx_hash
+            if ordering:
+                self._fill_ordering(pagination_meta.ordering, ordering, name)
 
         meta = queries_pb2.QueryPayloadMeta()
         meta.created_time = created_time
@@ -278,19 +713,80 @@ class Iroha(object):
         query_wrapper = queries_pb2.Query()
         query_wrapper.payload.meta.CopyFrom(meta)
         field_name = Iroha._camel_case_to_snake_case(name)
-        internal_query = getattr(query_wrapper.payload, field_name)
-        for key, value in kwargs.items():
-            if 'tx_hashes' == key:
-                hashes_attr = getattr(internal_query, key)
-                hashes_attr.extend(value)
-                continue
-            setattr(internal_query, key, value)
-        if pagination_meta:
-            pagination_meta_attr = getattr(internal_query, 'pagination_meta')
-            pagination_meta_attr.CopyFrom(pagination_meta)
-        if not len(kwargs):
-            message = getattr(queries_pb2, name)()
-            internal_query.CopyFrom(message)
+        try:
+            internal_query = getattr(query_wrapper.payload, field_name)
+            for key, value in kwargs.items():
+                if 'tx_hashes' == key:
+                    hashes_attr = getattr(internal_query, key)
+                    hashes_attr.extend(value)
+                    continue
+                setattr(internal_query, key, value)
+            if pagination_meta:
+                pagination_meta_attr = getattr(internal_query, 'pagination_meta')
+                pagination_meta_attr.CopyFrom(pagination_meta)
+            if not len(kwargs):
+                message = getattr(queries_pb2, name)()
+                internal_query.CopyFrom(message)
+        except (AttributeError, TypeError, ValueError) as e:
+            raise SerializationError.from_error(e, 'Cannot create {} query: {}'.format(name, e),
+                                                data={'query': name}) from e
+        return query_wrapper
+
+    @staticmethod
+    def _fill_ordering(message, ordering, query_name):
+        """
+        Fill protobuf Ordering with the sequence of field orderings
+        :param message: protobuf Ordering to fill
+        :param ordering: a list of (field, direction) tuples of symbolic names or enum values
+        :param query_name: name of the query for error reporting
+        :raise: SerializationError if a field or a direction is unknown
+        """
+        try:
+            for field, direction in ordering:
+                field_ordering = message.sequence.add()
+                field_ordering.field = queries_pb2.Field.Value(field) \
+                    if isinstance(field, str) else field
+                field_ordering.direction = queries_pb2.Direction.Value(direction) \
+                    if isinstance(direction, str) else direction
+        except (TypeError, ValueError) as e:
+            raise SerializationError('Cannot create {} query: invalid ordering {}: {}'.format(
+                query_name, ordering, e), data={'query': query_name}) from e
+
+    def query_from_dict(self, spec, counter=1, creator_account=None,
+                        created_time=None):
+        """
+        Creates a protobuf query from a plain dict, arguments are validated against the schema
+        :param spec: dict with CamelCased name of query under 'query' key,
+        the rest of keys are query arguments as they defined in schema,
+        nested messages (like pagination_meta) are dicts as well
+        :param counter: query counter, should be incremented for each new query
+        :param creator_account: account id of query creator
+        :param created_time: query creation timestamp in milliseconds or datetime.datetime
+        :return: a proto query
+        :raise: SerializationError if the query name or arguments do not match the schema
+
+        Usage example:
+        query = iroha.query_from_dict({
+            'query': 'GetAccountAssets',
+            'account_id': 'alice@test',
+            'pagination_meta': {'page_size': 10, 'first_asset_id': 'coin#test'}
+        })
+        """
+        arguments = dict(spec)
+        name = arguments.pop('query', None)
+        field_name = Iroha._camel_case_to_snake_case(name or '')
+        available = queries_pb2.Query.Payload.DESCRIPTOR.oneofs_by_name['query'].fields
+        if field_name not in [field.name for field in available]:
+            raise SerializationError('Unknown query name: {}'.format(name),
+                                     data={'query': name})
+        query_wrapper = self.query(name, counter=counter,
+                                   creator_account=creator_account,
+                                   created_time=created_time)
+        try:
+            json_format.ParseDict(arguments, getattr(query_wrapper.payload, field_name))
+        except json_format.ParseError as e:
+            raise SerializationError('Cannot create {} query: {}'.format(name, e),
+                                     data={'query': name}) from e
         return query_wrapper
 
     def blocks_query(self, counter=1, creator_account=None, created_time=None):
@@ -298,11 +794,12 @@ class Iroha(object):
         Creates a protobuf query for a blocks stream
         :param counter: query counter, should be incremented for each new query
         :param creator_account: account id of query creator
-        :param created_time: query creation timestamp in milliseconds
+        :param created_time: query creation timestamp in milliseconds or datetime.datetime
         :return: a proto blocks query
         """
         if not created_time:
             created_time = self.now()
+        created_time = self._timestamp(created_time)
         if not creator_account:
             creator_account = self.creator_account
 
@@ -335,32 +832,436 @@ class Iroha(object):
         for transaction in transactions:
             transaction.payload.batch.CopyFrom(meta)
 
+    # message types which can be exported and imported by name
+    EXPORTABLE_TYPES = {
+        'Transaction': transaction_pb2.Transaction,
+        'Query': queries_pb2.Query,
+        'BlocksQuery': queries_pb2.BlocksQuery,
+    }
+
+    @staticmethod
+    def to_json(proto):
+        """
+        Export a transaction or a query, signed or not, to JSON, e.g. to review it before signing
+        :param proto: protobuf Transaction, Query or BlocksQuery
+        :return: JSON string with snake_cased field names
+        """
+        return json_format.MessageToJson(proto, preserving_proto_field_name=True)
+
+    @staticmethod
+    def from_json(data, message_type='Transaction'):
+        """
+        Import a transaction or a query exported with Iroha.to_json
+        :param data: JSON string
+        :param message_type: 'Transaction', 'Query', 'BlocksQuery' or a protobuf message class
+        :return: protobuf message, its hash and signatures are the same as of the exported one
+        :raise: SerializationError if the JSON does not match the message schema
+        """
+        message_class = Iroha._exportable_type(message_type)
+        try:
+            return json_format.Parse(data, message_class())
+        except json_format.ParseError as e:
+            raise SerializationError('Cannot import {}: {}'.format(
+                message_class.DESCRIPTOR.name, e)) from e
+
+    @staticmethod
+    def to_bytes(proto):
+        """
+        Export a transaction or a query to the protobuf binary encoding used on the wire
+        :param proto: protobuf Transaction, Query or BlocksQuery
+        :return: bytes
+        """
+        return proto.SerializeToString()
+
+    @staticmethod
+    def from_bytes(data, message_type='Transaction'):
+        """
+        Import a transaction or a query exported with Iroha.to_bytes
+        :param data: bytes
+        :param message_type: 'Transaction', 'Query', 'BlocksQuery' or a protobuf message class
+        :return: protobuf message
+        :raise: SerializationError if the bytes cannot be decoded
+        """
+        message_class = Iroha._exportable_type(message_type)
+        try:
+            return message_class.FromString(data)
+        except DecodeError as e:
+            raise SerializationError('Cannot import {}: {}'.format(
+                message_class.DESCRIPTOR.name, e)) from e
 
-class IrohaGrpc(object):
+    @staticmethod
+    def _exportable_type(message_type):
+        if not isinstance(message_type, str):
+            return message_type
+        if message_type not in Iroha.EXPORTABLE_TYPES:
+            raise SerializationError('Unknown message type: {}'.format(message_type),
+                                     data={'message_type': message_type})
+        return Iroha.EXPORTABLE_TYPES[message_type]
+
+
+class TransactionBuilder(object):
     """
-    Possible implementation of gRPC transport to Iroha
+    Step by step construction of a transaction independent of any client.
+    Every step returns the builder, so the calls can be chained
+
+    Usage example:
+    tx = TransactionBuilder('admin@test') \
+        .command('CreateDomain', domain_id='domain', default_role='user') \
+        .quorum(2) \
+        .sign(admin_private_key, second_private_key)
+    net.send_tx(tx)
     """
 
-    def __init__(self, address=None, timeout=None, secure=False, *, max_message_length=None):
+    def __init__(self, creator_account, iroha=None):
+        """
+        :param creator_account: id of transaction creator account
+        :param iroha: Iroha instance to build the transaction with, e.g. to apply its datetime policy
+        """
+        self._iroha = iroha if iroha else Iroha(creator_account)
+        self._creator_account = creator_account
+        self._commands = []
+        self._quorum = 1
+        self._created_time = None
+
+    def append(self, *commands):
+        """
+        Add commands created via Iroha.command
+        :param commands: protobuf Command messages
+        :return: the builder
+        """
+        self._commands.extend(commands)
+        return self
+
+    def command(self, name, **kwargs):
+        """
+        Create and add a command, see Iroha.command
+        :param name: CamelCased name of command
+        :param kwargs: command arguments as they defined in schema
+        :return: the builder
+        :raise: SerializationError if the command name or arguments do not match the schema
+        """
+        return self.append(Iroha.command(name, **kwargs))
+
+    def quorum(self, quorum):
+        """
+        :param quorum: required number of signatures
+        :return: the builder
+        """
+        self._quorum = quorum
+        return self
+
+    def created_time(self, created_time):
+        """
+        :param created_time: transaction creation timestamp in milliseconds or datetime.datetime,
+        the time of build() call is used if omitted
+        :return: the builder
+        """
+        self._created_time = created_time
+        return self
+
+    def build(self):
+        """
+        Create an unsigned transaction, the builder can be reused afterwards
+        :return: a proto transaction
+        """
+        assert self._commands, 'At least one command has to be added'
+        return self._iroha.transaction(list(self._commands), self._quorum,
+                                       self._creator_account, self._created_time)
+
+    def sign(self, *private_keys):
+        """
+        Create a transaction signed with the keys
+        :param private_keys: hex strings of private keys to sign the transaction
+        :return: a proto transaction
+        """
+        return IrohaCrypto.sign_transaction(self.build(), *private_keys)
+
+
+class RetryPolicy(object):
+    """
+    Retries of failed unary gRPC calls with exponential backoff and jitter.
+    Every attempt gets the full timeout of the call unless the policy has a deadline.
+    Resending the same transaction is safe: Iroha processes a transaction hash only once
+
+    Usage example:
+    net = IrohaGrpc('127.0.0.1:50051', timeout=5,
+                    retry_policy=RetryPolicy(max_attempts=5, initial_backoff=0.2))
+    """
+
+    DEFAULT_RETRY_ON = (grpc.StatusCode.UNAVAILABLE,
+                        grpc.StatusCode.RESOURCE_EXHAUSTED,
+                        grpc.StatusCode.DEADLINE_EXCEEDED)
+
+    def __init__(self, max_attempts=3, initial_backoff=0.1, max_backoff=5.0, multiplier=2.0,
+                 jitter=0.2, retry_on=DEFAULT_RETRY_ON):
+        """
+        :param max_attempts: total number of attempts including the first one, 1 disables retries
+        :param initial_backoff: delay before the first retry in seconds
+        :param max_backoff: upper bound of the delay in seconds
+        :param multiplier: growth factor of the delay after each attempt
+        :param jitter: relative random deviation of the delay, 0.2 means +-20%
+        :param retry_on: grpc.StatusCode values of the errors worth retrying
+        """
+        if max_attempts < 1:
+            raise ConfigError('max_attempts should be at least 1, got {}'.format(max_attempts),
+                              data={'max_attempts': max_attempts})
+        self.max_attempts = max_attempts
+        self.initial_backoff = initial_backoff
+        self.max_backoff = max_backoff
+        self.multiplier = multiplier
+        self.jitter = jitter
+        self.retry_on = frozenset(retry_on)
+        self.deadline = None
+
+    def with_deadline(self, deadline):
+        """
+        :param deadline: time.monotonic() value all the attempts have to be finished by, None for no limit
+        :return: a copy of the policy capping the timeout of every attempt by the time left
+        and giving up once the next attempt could not be started before the deadline
+        """
+        policy = copy.copy(self)
+        policy.deadline = deadline
+        return policy
+
+    def attempt_timeout(self, timeout):
+        """
+        :param timeout: timeout of the call in seconds or None
+        :return: timeout of the next attempt in seconds
+        """
+        if self.deadline is None:
+            return timeout
+        remaining = max(self.deadline - time.monotonic(), 0.001)
+        return min(timeout, remaining) if timeout else remaining
+
+    def has_time_for(self, delay):
+        """
+        :param delay: backoff before the next attempt in seconds
+        :return: whether the next attempt would start before the deadline
+        """
+        return self.deadline is None or time.monotonic() + delay < self.deadline
+
+    def should_retry(self, error, attempt):
+        """
+        :param error: IrohaRpcError raised by the attempt
+        :param attempt: number of the failed attempt starting from 1
+        :return: whether one more attempt should be made
+        """
+        return attempt < self.max_attempts and error.code() in self.retry_on
+
+    def next_delay(self, error, attempt):
+        """
+        :param error: IrohaRpcError raised by the attempt
+        :param attempt: number of the failed attempt starting from 1
+        :return: delay in seconds before the next attempt, None if the call should not be retried
+        """
+        if not self.should_retry(error, attempt):
+            return None
+        delay = self.backoff(attempt)
+        return delay if self.has_time_for(delay) else None
+
+    def backoff(self, attempt):
+        """
+        :param attempt: number of the failed attempt starting from 1
+        :return: delay in seconds before the next attempt
+        """
+        delay = min(self.initial_backoff * self.multiplier ** (attempt - 1), self.max_backoff)
+        return max(delay * random.uniform(1 - self.jitter, 1 + self.jitter), 0)
+
+
+NO_RETRY = RetryPolicy(max_attempts=1)
+
+
+class IrohaStream(object):
+    """
+    Iterator over a gRPC server stream which can be shut down deterministically.
+    close() (or leaving the context manager) cancels the underlying call,
+    cancel() does the same and may be called from another thread to interrupt waiting
+    for the next item, in both cases the iteration just stops
+
+    Usage example:
+    with net.send_blocks_stream_query(query) as blocks:
+        for block in blocks:
+            if handle(block):
+                break
+    """
+
+    def __init__(self, call, items):
+        """
+        :param call: the grpc call object of the stream, its cancel() is used if any
+        :param items: iterator over the items produced from the call responses
+        """
+        self._call = call
+        self._items = items
+        self._cancelled = False
+
+    @property
+    def cancelled(self):
+        """Whether the stream was closed or cancelled by the client"""
+        return self._cancelled
+
+    def __iter__(self):
+        return self
+
+    def __next__(self):
+        if self._cancelled:
+            raise StopIteration
+        try:
+            return next(self._items)
+        except IrohaRpcError:
+            if self._cancelled:
+                raise StopIteration
+            raise
+
+    def cancel(self):
+        """
+        Cancel the underlying call, safe to be called from any thread
+        :return: None
+        """
+        self._cancelled = True
+        cancel = getattr(self._call, 'cancel', None)
+        if callable(cancel):
+            cancel()
+
+    def close(self):
+        """
+        Cancel the underlying call and release the stream
+        :return: None
+        """
+        self.cancel()
+        self._items.close()
+
+    def __enter__(self):
+        return self
+
+    def __exit__(self, exc_type, exc_value, traceback):
+        self.close()
+        return False
+
+
+class StreamQueue(asyncio.Queue):
+    """
+    asyncio.Queue filled with items of a blocking stream from a background thread.
+    The thread waits while a bounded queue is full, cancel() stops it and cancels the stream.
+    The items are followed by an exception instance if the stream failed,
+    and by None as the end of stream marker
+
+    Usage example:
+    queue = net.blocks_stream_queue(query, maxsize=10)
+    block = await queue.get()
+    queue.cancel()
+    """
+
+    def __init__(self, stream, maxsize=0, loop=None):
+        """
+        :param stream: an iterable to be consumed, its cancel() is used if any
+        :param maxsize: maximum size of the queue, 0 means unlimited
+        :param loop: asyncio event loop the queue belongs to, the current one is used if omitted
+        """
+        super().__init__(maxsize=maxsize)
+        self._stream = stream
+        self._loop = loop if loop else asyncio.get_event_loop()
+        self._lock = threading.Lock()
+        self._pending = None
+        self._cancelled = False
+        self._thread = threading.Thread(target=self._pump, daemon=True)
+        self._thread.start()
+
+    @property
+    def cancelled(self):
+        """Whether the queue filling was stopped by cancel() or because the event loop was closed"""
+        return self._cancelled
+
+    def _put_from_thread(self, item):
+        """
+        :return: whether the item was put, False if the queue was cancelled or the loop is closed
+        """
+        with self._lock:
+            if self._cancelled:
+                return False
+            coroutine = self.put(item)
+            try:
+                self._pending = asyncio.run_coroutine_threadsafe(coroutine, self._loop)
+            except RuntimeError:
+                # the event loop is closed, nobody is going to read the queue
+                coroutine.close()
+                pending = None
+            else:
+                pending = self._pending
+        if pending is None:
+            self.cancel()
+            return False
+        try:
+            pending.result()
+        except concurrent.futures.CancelledError:
+            return False
+        return True
+
+    def _pump(self):
+        try:
+            for item in self._stream:
+                if not self._put_from_thread(item):
+                    return
+        except Exception as e:
+            if not self._put_from_thread(e):
+                return
+        self._put_from_thread(None)
+
+    def cancel(self):
+        """
+        Stop filling the queue and cancel the stream, safe to be called from any thread
+        :return: None
+        """
+        with self._lock:
+            self._cancelled = True
+            pending = self._pending
+        if pending is not None:
+            pending.cancel()
+        cancel = getattr(self._stream, 'cancel', None)
+        if callable(cancel):
+            cancel()
+
+
+class _IrohaGrpcBase(object):
+    """
+    Client state shared by IrohaGrpc and iroha.aio.IrohaGrpcAsync:
+    the channel, the stubs, the retry policy, the metadata and the streams to be cancelled on close
+    """
+
+    # module the channels are created with, grpc.aio for the asyncio client
+    _channels = grpc
+
+    def __init__(self, address=None, timeout=None, secure=False, *, max_message_length=None,
+                 channel=None, retry_policy=None, proxy=None, metadata=None):
         """
         Create Iroha gRPC client
-        :param address: Iroha Torii address with port, example "127.0.0.1:50051"
+        :param address: Iroha Torii address with port, example "127.0.0.1:50051",
+        Unix domain sockets are supported as well, example "unix:///var/run/iroha/torii.sock"
         :param timeout: timeout for network I/O operations in seconds
         :param secure: enable grpc ssl channel
         :param max_message_length: it is max message length in bytes for grpc
+        :param channel: a ready to use grpc.Channel (grpc.aio.Channel for the asyncio client)
+        to send requests through, address, secure and max_message_length are ignored when it is passed.
+        Allows plugging in custom transports: an intercepted channel, a channel to a tunnel
+        or any object implementing grpc.Channel methods unary_unary and unary_stream
+        :param retry_policy: RetryPolicy for submissions, queries and status requests,
+        failed calls are not retried if omitted
+        :param proxy: HTTP CONNECT proxy to reach the peer through, example "http://proxy:3128",
+        False disables proxying, when omitted grpc follows grpc_proxy, https_proxy, http_proxy
+        and no_proxy environment variables
+        :param metadata: a list of (key, value) pairs sent as gRPC metadata (HTTP/2 headers)
+        with every call, e.g. [('authorization', 'Bearer <token>')] for authenticating gateways,
+        keys have to be lowercase
         """
         self._address = address if address else '127.0.0.1:50051'
+        self._retry_policy = retry_policy if retry_policy else NO_RETRY
+        self._metadata = tuple(metadata) if metadata else ()
 
-        channel_kwargs = {}
-        if max_message_length is not None:
-            channel_kwargs['options'] = [
-                ('grpc.max_send_message_length', max_message_length),
-                ('grpc.max_receive_message_length', max_message_length)]
-
-        if secure:
-            self._channel = grpc.secure_channel(self._address, grpc.ssl_channel_credentials(), **channel_kwargs)
+        self._owns_channel = channel is None
+        if channel is not None:
+            self._channel = channel
         else:
-            self._channel = grpc.insecure_channel(self._address, **channel_kwargs)
+            self._channel = self._create_channel(secure, max_message_length, proxy)
+        self._streams = weakref.WeakSet()
 
         self._timeout = timeout
         self._command_service_stub = endpoint_pb2_grpc.CommandService_v1Stub(
@@ -368,111 +1269,722 @@ class IrohaGrpc(object):
         self._query_service_stub = endpoint_pb2_grpc.QueryService_v1Stub(
             self._channel)
 
-    def send_tx(self, transaction, timeout=None):
+    def _create_channel(self, secure, max_message_length, proxy=None):
+        """
+        Create grpc channel to Torii address
+        :param secure: enable grpc ssl channel
+        :param max_message_length: it is max message length in bytes for grpc
+        :param proxy: proxy address, False to disable proxying, None to follow the environment
+        :return: grpc.Channel, grpc.aio.Channel for the asyncio client
+        """
+        channel_kwargs = {}
+        options = self._channel_options(max_message_length, proxy)
+        if options:
+            channel_kwargs['options'] = options
+
+        if secure:
+            return self._channels.secure_channel(self._address, grpc.ssl_channel_credentials(),
+                                                 **channel_kwargs)
+        return self._channels.insecure_channel(self._address, **channel_kwargs)
+
+    @staticmethod
+    def _channel_options(max_message_length=None, proxy=None):
+        """
+        :param max_message_length: it is max message length in bytes for grpc
+        :param proxy: proxy address, False to disable proxying, None to follow the environment
+        :return: list of grpc channel options
+        """
+        options = []
+        if max_message_length is not None:
+            options.extend([
+                ('grpc.max_send_message_length', max_message_length),
+                ('grpc.max_receive_message_length', max_message_length)])
+        if proxy is False:
+            options.append(('grpc.enable_http_proxy', 0))
+        elif proxy:
+            options.append(('grpc.http_proxy', proxy))
+        return options
+
+    def _track(self, stream):
+        """
+        Remember a stream to be cancelled on close
+        :param stream: IrohaStream, StreamQueue or iroha.aio.AsyncIrohaStream
+        :return: the stream
+        """
+        self._streams.add(stream)
+        return stream
+
+    def _call_metadata(self, metadata=None):
+        """
+        :param metadata: per call metadata pairs added to the client ones
+        :return: tuple of metadata pairs or None if there are none
+        """
+        combined = self._metadata + tuple(metadata or ())
+        return combined if combined else None
+
+
+class IrohaGrpc(_IrohaGrpcBase):
+    """
+    Possible implementation of gRPC transport to Iroha.
+    Can be used as a context manager closing the client on exit
+
+    Usage example:
+    with IrohaGrpc('127.0.0.1:50051') as net:
+        net.send_tx(tx)
+    """
+
+    def close(self):
+        """
+        Cancel the open streams and close the channel if it was created by the client,
+        a channel passed to the constructor is left open
+        :return: None
+        """
+        for stream in list(self._streams):
+            stream.cancel()
+        if self._owns_channel:
+            self._channel.close()
+
+    def __enter__(self):
+        return self
+
+    def __exit__(self, exc_type, exc_value, traceback):
+        self.close()
+        return False
+
+    @staticmethod
+    @contextlib.contextmanager
+    def _rpc_errors(timeout=None, may_be_in_flight=False, tx_hashes=(), query_hash=None):
+        """
+        Translate grpc.RpcError raised inside the context to IrohaRpcError subclass
+        corresponding to the status code
+        :param timeout: timeout of the call to be reported in IrohaTimeoutError
+        :param may_be_in_flight: whether a transaction could be still processed by the peer
+        when the call times out
+        :param tx_hashes: hex hashes of the transactions the call is about
+        :param query_hash: hex hash of the query the call is about
+        """
+        started = time.monotonic()
+        try:
+            yield
+        except IrohaRpcError:
+            raise
+        except grpc.RpcError as e:
+            code = IrohaGrpc._rpc_code(e)
+            if code == grpc.StatusCode.DEADLINE_EXCEEDED:
+                raise IrohaTimeoutError(e, timeout, time.monotonic() - started,
+                                        may_be_in_flight, tx_hashes, query_hash) from e
+            error_class = _RPC_ERRORS.get(code, IrohaRpcError)
+            raise error_class(e, tx_hashes, query_hash) from e
+
+    @staticmethod
+    @contextlib.contextmanager
+    def _overall_deadline(timeout):
+        """
+        Deadline of a blocking call made of several requests,
+        IrohaTimeoutError raised inside the context reports the timeout and the time elapsed
+        of the whole call instead of the ones of the request which timed out
+        :param timeout: overall timeout in seconds or None
+        :return: context manager yielding the deadline as time.monotonic() value or None
+        """
+        started = time.monotonic()
+        try:
+            yield started + timeout if timeout else None
+        except IrohaTimeoutError as e:
+            if timeout:
+                e.deadline = timeout
+                e.elapsed = time.monotonic() - started
+                e.data.update(deadline=e.deadline, elapsed=e.elapsed)
+            raise
+
+    @staticmethod
+    def _hex_payload_hash(proto_with_payload):
+        return binascii.hexlify(IrohaCrypto.hash(proto_with_payload)).decode('ascii')
+
+    def _unary(self, method, request, timeout, retry_policy=None, metadata=None, **error_context):
+        """
+        Make a unary call retrying it according to the retry policy
+        :param method: stub method to call
+        :param request: protobuf request message
+        :param timeout: timeout of each attempt in seconds
+        :param retry_policy: RetryPolicy overriding the client one
+        :param metadata: per call metadata pairs
+        :param error_context: arguments of _rpc_errors describing the call
+        :return: protobuf response message
+        :raise: IrohaRpcError of the last attempt
+        """
+        policy = retry_policy if retry_policy else self._retry_policy
+        metadata = self._call_metadata(metadata)
+        attempt = 1
+        while True:
+            attempt_timeout = policy.attempt_timeout(timeout)
+            try:
+                with self._rpc_errors(attempt_timeout, **error_context):
+                    return method(request, timeout=attempt_timeout, metadata=metadata)
+            except IrohaRpcError as e:
+                delay = policy.next_delay(e, attempt)
+                if delay is None:
+                    raise
+            time.sleep(delay)
+            attempt += 1
+
+    @staticmethod
+    def _rpc_code(rpc_error):
+        code = getattr(rpc_error, 'code', None)
+        return code() if callable(code) else grpc.StatusCode.UNKNOWN
+
+    def send_tx(self, transaction, timeout=None, retry_policy=None, metadata=None):
         """
         Send a transaction to Iroha
         :param transaction: protobuf Transaction
         :param timeout: timeout for network I/O operations in seconds
+        :param retry_policy: RetryPolicy overriding the client one for this call
+        :param metadata: a list of (key, value) pairs sent as gRPC metadata in addition to the client ones
         :return: None
-        :raise: grpc.RpcError with .code() available in case of any error
+        :raise: IrohaRpcError (a grpc.RpcError with .code() available) in case of any error,
+        IrohaTimeoutError if the timeout has expired
         """
         if not timeout:
             timeout = self._timeout
-        self._command_service_stub.Torii(transaction, timeout=timeout)
+        self._unary(self._command_service_stub.Torii, transaction, timeout, retry_policy, metadata,
+                    may_be_in_flight=True, tx_hashes=[self._hex_payload_hash(transaction)])
 
-    def send_txs(self, transactions, timeout=None):
+    def send_tx_bytes(self, data, timeout=None, retry_policy=None, metadata=None,
+                      verify_signatures=True):
+        """
+        Send an already signed and serialized transaction to Iroha, e.g. produced by another SDK
+        :param data: bytes of protobuf Transaction
+        :param timeout: timeout for network I/O operations in seconds
+        :param retry_policy: RetryPolicy overriding the client one for this call
+        :param metadata: a list of (key, value) pairs sent as gRPC metadata in addition to the client ones
+        :param verify_signatures: check locally that all the signatures match the payload
+        :return: the decoded protobuf Transaction
+        :raise: SerializationError if the bytes are not a signed transaction
+        or a signature does not match, IrohaRpcError (a grpc.RpcError with .code() available)
+        in case of any error, IrohaTimeoutError if the timeout has expired
+        """
+        transaction = self._decode_signed_tx(data, verify_signatures)
+        self.send_tx(transaction, timeout, retry_policy, metadata)
+        return transaction
+
+    @staticmethod
+    def _decode_signed_tx(data, verify_signatures=True):
+        """
+        :param data: bytes of protobuf Transaction
+        :param verify_signatures: check that all the signatures match the payload
+        :return: protobuf Transaction
+        :raise: SerializationError if the bytes are not a signed transaction or a signature does not match
+        """
+        try:
+            transaction = transaction_pb2.Transaction.FromString(data)
+        except DecodeError as e:
+            raise SerializationError('Cannot decode a transaction: {}'.format(e)) from e
+        tx_hash = IrohaGrpc._hex_payload_hash(transaction)
+        if not transaction.payload.reduced_payload.commands or not transaction.signatures:
+            raise SerializationError('Transaction {} has no commands or signatures'.format(tx_hash),
+                                     data={'tx_hash': tx_hash})
+        if verify_signatures:
+            for signature in transaction.signatures:
+                if not IrohaGrpc._is_signature_valid(transaction, signature):
+                    raise SerializationError(
+                        'Transaction {} has invalid signature of {}'.format(tx_hash, signature.public_key),
+                        data={'tx_hash': tx_hash, 'public_key': signature.public_key})
+        return transaction
+
+    @staticmethod
+    def _is_signature_valid(transaction, signature):
+        # multihash prefixed keys are ed25519 with sha2, the rest are the legacy sha3 ones
+        if not signature.public_key.startswith('ed0120'):
+            return IrohaCrypto.is_signature_valid(transaction, signature)
+        try:
+            return IrohaCrypto.is_sha2_signature_valid(transaction, signature)
+        except (nacl.exceptions.BadSignatureError, ValueError):
+            return False
+
+    def send_txs(self, transactions, timeout=None, retry_policy=None, metadata=None):
         """
         Send a series of transactions to Iroha at once.
         Useful for submitting batches of transactions.
         :param transactions: list of protobuf transactions to be sent
         :param timeout: timeout for network I/O operations in seconds
+        :param retry_policy: RetryPolicy overriding the client one for this call
+        :param metadata: a list of (key, value) pairs sent as gRPC metadata in addition to the client ones
         :return: None
-        :raise: grpc.RpcError with .code() available in case of any error
+        :raise: IrohaRpcError (a grpc.RpcError with .code() available) in case of any error,
+        IrohaTimeoutError if the timeout has expired
         """
         if not timeout:
             timeout = self._timeout
+        tx_list, tx_hashes = self._tx_list(transactions)
+        self._unary(self._command_service_stub.ListTorii, tx_list, timeout, retry_policy, metadata,
+                    may_be_in_flight=True, tx_hashes=tx_hashes)
+
+    @staticmethod
+    def _tx_list(transactions):
+        """
+        :param transactions: list of protobuf transactions
+        :return: protobuf TxList with the transactions and the list of their hex hashes
+        """
         tx_list = endpoint_pb2.TxList()
         tx_list.transactions.extend(transactions)
-        self._command_service_stub.ListTorii(tx_list, timeout=timeout)
+        return tx_list, [IrohaGrpc._hex_payload_hash(tx) for tx in transactions]
+
+    def send_tx_blocking(self, transaction, timeout=None, raise_on_rejection=False, metadata=None):
+        """
+        Send a transaction to Iroha and wait until it is committed or rejected
+        :param transaction: protobuf Transaction
+        :param timeout: overall time to wait in seconds, including the submission
+        :param raise_on_rejection: raise TransactionRejected (or its subclass describing the reason)
+        if the transaction failed validation or has been rejected
+        :param metadata: a list of (key, value) pairs sent as gRPC metadata with every request
+        in addition to the client ones
+        :return: a tuple with the final symbolic status description,
+        integral status code, and error code (will be 0 if no error occurred)
+        :raise: IrohaRpcError (a grpc.RpcError with .code() available) in case of any error,
+        IrohaTimeoutError if the transaction has not reached a final status in time,
+        TransactionRejected if raise_on_rejection is set
+        """
+        if not timeout:
+            timeout = self._timeout
+        with self._overall_deadline(timeout) as deadline:
+            self.send_tx(transaction, timeout, self._retry_policy.with_deadline(deadline), metadata)
+            return self.wait_for_tx(transaction, self._remaining(deadline), raise_on_rejection,
+                                    metadata)
+
+    def wait_for_tx(self, transaction, timeout=None, raise_on_rejection=False, metadata=None):
+        """
+        Wait until a sent transaction is committed or rejected
+        :param transaction: the transaction, which final status is about to be known
+        :param timeout: time to wait in seconds
+        :param raise_on_rejection: raise TransactionRejected (or its subclass describing the reason)
+        if the transaction failed validation or has been rejected
+        :param metadata: a list of (key, value) pairs sent as gRPC metadata with every request
+        in addition to the client ones
+        :return: a tuple with the final symbolic status description,
+        integral status code, and error code (will be 0 if no error occurred)
+        :raise: IrohaRpcError (a grpc.RpcError with .code() available) in case of any error,
+        IrohaTimeoutError if the transaction has not reached a final status in time,
+        TransactionRejected if raise_on_rejection is set
+        """
+        with self._overall_deadline(timeout) as deadline:
+            status = None
+            statuses = self.tx_status_stream(transaction, timeout, raise_on_rejection, metadata)
+            with contextlib.closing(statuses):
+                for status in statuses:
+                    if status[1] in self.FINAL_STATUSES:
+                        return status
+            # the stream is closed by the peer without a final status, e.g. for MST_PENDING
+            if status:
+                return status
+            return self.tx_status(transaction, self._remaining(deadline), raise_on_rejection,
+                                  self._retry_policy.with_deadline(deadline), metadata)
 
-    def send_query(self, query, timeout=None):
+    def send_txs_blocking(self, transactions, timeout=None, metadata=None):
+        """
+        Send independent transactions to Iroha at once and wait for their final statuses
+        :param transactions: list of protobuf transactions to be sent
+        :param timeout: overall time to wait in seconds, including the submission
+        :param metadata: a list of (key, value) pairs sent as gRPC metadata with every request
+        in addition to the client ones
+        :return: collections.OrderedDict mapping hex hashes of the transactions in the original order
+        to tuples with the final symbolic status description, integral status code and error code,
+        or to None for the transactions which have not reached a final status in time
+        :raise: IrohaRpcError (a grpc.RpcError with .code() available) if the submission failed
+        or a status could not be requested
+        """
+        if not timeout:
+            timeout = self._timeout
+        with self._overall_deadline(timeout) as deadline:
+            self.send_txs(transactions, timeout, self._retry_policy.with_deadline(deadline),
+                          metadata)
+            statuses = []
+            for transaction in transactions:
+                try:
+                    statuses.append(self.wait_for_tx(transaction, self._remaining(deadline),
+                                                     metadata=metadata))
+                except IrohaTimeoutError:
+                    statuses.append(None)
+        return self._tx_report(transactions, statuses)
+
+    @staticmethod
+    def _tx_report(transactions, statuses):
+        """
+        :param transactions: list of protobuf transactions
+        :param statuses: final status tuples of the transactions or None for unknown ones
+        :return: collections.OrderedDict mapping hex hashes of the transactions to the statuses
+        """
+        return collections.OrderedDict(
+            (IrohaGrpc._hex_payload_hash(transaction), status)
+            for transaction, status in zip(transactions, statuses))
+
+    @staticmethod
+    def _remaining(deadline):
+        """
+        :param deadline: time.monotonic() value or None
+        :return: seconds left until the deadline (at least a millisecond), None if there is no deadline
+        """
+        if deadline is None:
+            return None
+        return max(deadline - time.monotonic(), 0.001)
+
+    def send_query(self, query, timeout=None, raise_on_error=False, retry_policy=None,
+                   metadata=None):
         """
         Send a query to Iroha
         :param query: protobuf Query
         :param timeout: timeout for network I/O operations in seconds
+        :param raise_on_error: raise QueryError instead of returning ErrorResponse
+        :param retry_policy: RetryPolicy overriding the client one for this call
+        :param metadata: a list of (key, value) pairs sent as gRPC metadata in addition to the client ones
         :return: a protobuf response to the query
-        :raise: grpc.RpcError with .code() available in case of any error
+        :raise: IrohaRpcError (a grpc.RpcError with .code() available) in case of any error,
+        IrohaTimeoutError if the timeout has expired,
+        QueryError if raise_on_error is set and Iroha responded with ErrorResponse
         """
         if not timeout:
             timeout = self._timeout
-        response = self._query_service_stub.Find(query, timeout=timeout)
+        response = self._unary(self._query_service_stub.Find, query, timeout, retry_policy, metadata,
+                               query_hash=self._hex_payload_hash(query))
+        return self._checked_query_response(response, raise_on_error)
+
+    @staticmethod
+    def _checked_query_response(response, raise_on_error):
+        """
+        :param response: protobuf QueryResponse
+        :param raise_on_error: raise QueryError instead of returning ErrorResponse
+        :return: the response
+        """
+        if raise_on_error and response.HasField('error_response'):
+            raise QueryError(response)
         return response
 
-    def send_blocks_stream_query(self, query, timeout=None):
+    @staticmethod
+    def _detail_records(response):
+        for writer, details in json.loads(response.detail or '{}').items():
+            for key, value in details.items():
+                yield writer, key, value
+
+    @staticmethod
+    def _set_detail_cursor(meta, record_id):
+        meta.first_record_id.CopyFrom(record_id)
+
+    # query name -> (response field, items of a page, cursor of the next page or None,
+    #                setter of the cursor in the pagination meta)
+    PAGINATED_QUERIES = {
+        'GetAccountTransactions': (
+            'transactions_page_response', lambda r: r.transactions,
+            lambda r: r.next_tx_hash if r.HasField('next_tx_hash') else None,
+            lambda meta, cursor: setattr(meta, 'first_tx_hash', cursor)),
+        'GetAccountAssetTransactions': (
+            'transactions_page_response', lambda r: r.transactions,
+            lambda r: r.next_tx_hash if r.HasField('next_tx_hash') else None,
+            lambda meta, cursor: setattr(meta, 'first_tx_hash', cursor)),
+        'GetPendingTransactions': (
+            'pending_transactions_page_response', lambda r: r.transactions,
+            lambda r: r.next_batch_info.first_tx_hash or None,
+            lambda meta, cursor: setattr(meta, 'first_tx_hash', cursor)),
+        'GetAccountAssets': (
+            'account_assets_response', lambda r: r.account_assets,
+            lambda r: r.next_asset_id if r.HasField('next_asset_id') else None,
+            lambda meta, cursor: setattr(meta, 'first_asset_id', cursor)),
+        'GetAccountDetail': (
+            'account_detail_response', lambda r: IrohaGrpc._detail_records(r),
+            lambda r: r.next_record_id if r.HasField('next_record_id') else None,
+            lambda meta, cursor: IrohaGrpc._set_detail_cursor(meta, cursor)),
+    }
+
+    def query_iter(self, iroha, private_key, name, page_size=100, counter=1, timeout=None,
+                   metadata=None, **kwargs):
+        """
+        Generator of results of a paginated query, the pages are requested lazily one by one
+        so only a single page is kept in memory
+        :param iroha: Iroha instance with creator account set to build the queries with
+        :param private_key: private key of the creator account to sign the queries with
+        :param name: CamelCased name of the query, one of IrohaGrpc.PAGINATED_QUERIES
+        :param page_size: number of results requested at once
+        :param counter: query counter of the first page, incremented for each next page
+        :param timeout: timeout for network I/O operations in seconds for each page
+        :param metadata: a list of (key, value) pairs sent as gRPC metadata with every page request
+        in addition to the client ones
+        :param kwargs: query arguments as they defined in schema, except pagination_meta
+        :return: an iterable over protobuf Transaction messages for transactions queries,
+        protobuf AccountAsset messages for GetAccountAssets
+        and (writer, key, value) tuples for GetAccountDetail
+        :raise: IrohaRpcError (a grpc.RpcError with .code() available) in case of any error,
+        QueryError if Iroha responded with ErrorResponse,
+        SerializationError if the query is not paginated or the arguments do not match the schema
+
+        Usage example:
+        for asset in net.query_iter(iroha, private_key, 'GetAccountAssets', account_id='alice@test'):
+            print(asset.asset_id, asset.balance)
+        """
+        cursor = None
+        while True:
+            query = self._page_query(iroha, private_key, name, page_size, counter, cursor, kwargs)
+            items, cursor = self._page(name, self.send_query(query, timeout, raise_on_error=True,
+                                                             metadata=metadata))
+            for item in items:
+                yield item
+            if not cursor:
+                return
+            counter += 1
+
+    @staticmethod
+    def _page_query(iroha, private_key, name, page_size, counter, cursor, query_args):
+        """
+        :param cursor: cursor of the page returned with the previous one, None for the first page
+        :param query_args: query arguments as they defined in schema, except pagination_meta
+        :return: signed protobuf Query requesting a page of a paginated query
+        :raise: SerializationError if the query is not paginated or the arguments do not match the schema
+        """
+        if name not in IrohaGrpc.PAGINATED_QUERIES:
+            raise SerializationError('{} query is not paginated'.format(name), data={'query': name})
+        query = iroha.query(name, counter=counter, **query_args)
+        meta = getattr(query.payload, Iroha._camel_case_to_snake_case(name)).pagination_meta
+        meta.page_size = page_size
+        if cursor is not None:
+            IrohaGrpc.PAGINATED_QUERIES[name][3](meta, cursor)
+        IrohaCrypto.sign_query(query, private_key)
+        return query
+
+    @staticmethod
+    def _page(name, response):
+        """
+        :param name: CamelCased name of the paginated query
+        :param response: protobuf QueryResponse to the page query
+        :return: an iterable over the items of the page and the cursor of the next page or None
+        """
+        field, items, next_cursor, _ = IrohaGrpc.PAGINATED_QUERIES[name]
+        page = getattr(response, field)
+        return items(page), next_cursor(page)
+
+    # GetTransactions error code for hashes unknown to the query creator
+    UNKNOWN_TX_HASH_ERROR_CODE = 4
+
+    def get_transaction(self, iroha, private_key, tx_hash, counter=1, timeout=None,
+                        as_dict=False, metadata=None):
+        """
+        Fetch a committed transaction by its hash
+        :param iroha: Iroha instance with creator account set to build the query with
+        :param private_key: private key of the creator account to sign the query with
+        :param tx_hash: hex string or bytes hash of the transaction
+        :param counter: query counter
+        :param timeout: timeout for network I/O operations in seconds
+        :param as_dict: return the transaction as a plain dict with commands,
+        signatures and metadata instead of protobuf message
+        :param metadata: a list of (key, value) pairs sent as gRPC metadata in addition to the client ones
+        :return: protobuf Transaction (or dict) or None if the transaction is unknown
+        or not accessible by the query creator
+        :raise: IrohaRpcError (a grpc.RpcError with .code() available) in case of any error,
+        QueryError if Iroha responded with any other error
+        """
+        query = self._transaction_query(iroha, private_key, tx_hash, counter)
+        return self._found_transaction(self.send_query(query, timeout, metadata=metadata), as_dict)
+
+    @staticmethod
+    def _transaction_query(iroha, private_key, tx_hash, counter):
+        """
+        :param tx_hash: hex string or bytes hash of the transaction
+        :return: signed protobuf Query for the transaction
+        """
+        if isinstance(tx_hash, bytes):
+            tx_hash = binascii.hexlify(tx_hash).decode('ascii')
+        query = iroha.query('GetTransactions', counter=counter, tx_hashes=[tx_hash])
+        IrohaCrypto.sign_query(query, private_key)
+        return query
+
+    @staticmethod
+    def _found_transaction(response, as_dict):
+        """
+        :param response: protobuf QueryResponse to GetTransactions query for a single hash
+        :param as_dict: return the transaction as a plain dict
+        :return: protobuf Transaction (or dict) or None if the transaction is unknown
+        :raise: QueryError if Iroha responded with an error other than unknown hash
+        """
+        if response.HasField('error_response'):
+            if response.error_response.error_code == IrohaGrpc.UNKNOWN_TX_HASH_ERROR_CODE:
+                return None
+            raise QueryError(response)
+        transactions = response.transactions_response.transactions
+        if not transactions:
+            return None
+        if as_dict:
+            return json_format.MessageToDict(transactions[0], preserving_proto_field_name=True)
+        return transactions[0]
+
+    def send_blocks_stream_query(self, query, timeout=None, metadata=None):
         """
         Send a query for blocks stream to Iroha
         :param query: protobuf BlocksQuery
         :param timeout: timeout for network I/O operations in seconds
-        :return: an iterable over a stream of blocks
-        :raise: grpc.RpcError with .code() available in case of any error
+        :param metadata: a list of (key, value) pairs sent as gRPC metadata in addition to the client ones
+        :return: IrohaStream over a stream of blocks
+        :raise: IrohaRpcError (a grpc.RpcError with .code() available) in case of any error,
+        IrohaTimeoutError if the timeout has expired
         """
         if not timeout:
             timeout = self._timeout
-        response = self._query_service_stub.FetchCommits(
-            query, timeout=timeout)
-        for block in response:
-            yield block
+        errors = functools.partial(self._rpc_errors, timeout,
+                                   query_hash=self._hex_payload_hash(query))
+        with errors():
+            response = self._query_service_stub.FetchCommits(
+                query, timeout=timeout, metadata=self._call_metadata(metadata))
+
+        def blocks():
+            with errors():
+                for block in response:
+                    yield block
 
-    def tx_status(self, transaction, timeout=None):
+        return self._track(IrohaStream(response, blocks()))
+
+    def tx_status(self, transaction, timeout=None, raise_on_rejection=False, retry_policy=None,
+                  metadata=None):
         """
         Request a status of a transaction
         :param transaction: the transaction, which status is about to be known
         :param timeout: timeout for network I/O operations in seconds
+        :param raise_on_rejection: raise TransactionRejected (or its subclass describing the reason)
+        if the transaction failed validation or has been rejected
+        :param retry_policy: RetryPolicy overriding the client one for this call
+        :param metadata: a list of (key, value) pairs sent as gRPC metadata in addition to the client ones
         :return: a tuple with the symbolic status description,
         integral status code, and error code (will be 0 if no error occurred)
-        :raise: grpc.RpcError with .code() available in case of any error
+        :raise: IrohaRpcError (a grpc.RpcError with .code() available) in case of any error,
+        IrohaTimeoutError if the timeout has expired,
+        TransactionRejected if raise_on_rejection is set
         """
         if not timeout:
             timeout = self._timeout
-        request = endpoint_pb2.TxStatusRequest()
-        request.tx_hash = binascii.hexlify(IrohaCrypto.hash(transaction))
-        response = self._command_service_stub.Status(request, timeout=timeout)
-        return self._parse_tx_status(response)
+        request = self._tx_status_request(IrohaCrypto.hash(transaction))
+        response = self._unary(self._command_service_stub.Status, request, timeout, retry_policy,
+                               metadata, may_be_in_flight=True, tx_hashes=[request.tx_hash])
+        return self._tx_status_tuple(response, raise_on_rejection, transaction)
 
-    def tx_status_stream(self, transaction, timeout=None):
+    def tx_status_stream(self, transaction, timeout=None, raise_on_rejection=False, metadata=None):
         """
         Generator of transaction statuses from status stream
         :param transaction: the transaction, which status is about to be known
         :param timeout: timeout for network I/O operations in seconds
-        :return: an iterable over a series of tuples with symbolic status description,
+        :param raise_on_rejection: raise TransactionRejected (or its subclass describing the reason)
+        if the transaction failed validation or has been rejected
+        :param metadata: a list of (key, value) pairs sent as gRPC metadata in addition to the client ones
+        :return: IrohaStream over a series of tuples with symbolic status description,
         integral status code, and error code (will be 0 if no error occurred)
-        :raise: grpc.RpcError with .code() available in case of any error
+        :raise: IrohaRpcError (a grpc.RpcError with .code() available) in case of any error,
+        IrohaTimeoutError if the timeout has expired,
+        TransactionRejected if raise_on_rejection is set
         """
         tx_hash = IrohaCrypto.hash(transaction)
-        yield from self.tx_hash_status_stream(tx_hash, timeout)
+        return self._tx_hash_status_stream(tx_hash, timeout, raise_on_rejection, transaction,
+                                           metadata)
 
-    def tx_hash_status_stream(self, transaction_hash: "str or bytes", timeout=None):
+    def tx_hash_status_stream(self, transaction_hash: "str or bytes", timeout=None,
+                              raise_on_rejection=False, metadata=None):
         """
         Generator of transaction statuses from status stream
         :param transaction_hash: the hash of transaction, which status is about to be known
         :param timeout: timeout for network I/O operations in seconds
-        :return: an iterable over a series of tuples with symbolic status description,
+        :param raise_on_rejection: raise TransactionRejected (or its subclass describing the reason)
+        if the transaction failed validation or has been rejected
+        :param metadata: a list of (key, value) pairs sent as gRPC metadata in addition to the client ones
+        :return: IrohaStream over a series of tuples with symbolic status description,
         integral status code, and error code (will be 0 if no error occurred)
-        :raise: grpc.RpcError with .code() available in case of any error
+        :raise: IrohaRpcError (a grpc.RpcError with .code() available) in case of any error,
+        IrohaTimeoutError if the timeout has expired,
+        TransactionRejected if raise_on_rejection is set
         """
+        return self._tx_hash_status_stream(transaction_hash, timeout, raise_on_rejection,
+                                           metadata=metadata)
+
+    def _tx_hash_status_stream(self, transaction_hash, timeout, raise_on_rejection,
+                               transaction=None, metadata=None):
         if not timeout:
             timeout = self._timeout
+        request = self._tx_status_request(transaction_hash)
+        errors = functools.partial(self._rpc_errors, timeout, may_be_in_flight=True,
+                                   tx_hashes=[request.tx_hash])
+        with errors():
+            response = self._command_service_stub.StatusStream(
+                request, timeout=timeout, metadata=self._call_metadata(metadata))
+
+        def statuses():
+            with errors():
+                for status in response:
+                    yield self._tx_status_tuple(status, raise_on_rejection, transaction)
+
+        return self._track(IrohaStream(response, statuses()))
+
+    @staticmethod
+    def _tx_status_request(transaction_hash):
+        """
+        :param transaction_hash: hex string or bytes hash of the transaction
+        :return: protobuf TxStatusRequest
+        """
         request = endpoint_pb2.TxStatusRequest()
         if isinstance(transaction_hash, bytes):
             request.tx_hash = binascii.hexlify(transaction_hash)
         else:
             request.tx_hash = transaction_hash.encode('utf-8')
-        response = self._command_service_stub.StatusStream(
-            request, timeout=timeout)
-        for status in response:
-            status_name, status_code, error_code = self._parse_tx_status(
-                status)
-            yield status_name, status_code, error_code
+        return request
+
+    REJECTION_STATUSES = (endpoint_pb2.STATELESS_VALIDATION_FAILED,
+                          endpoint_pb2.STATEFUL_VALIDATION_FAILED,
+                          endpoint_pb2.REJECTED,
+                          endpoint_pb2.MST_EXPIRED)
+    FINAL_STATUSES = REJECTION_STATUSES + (endpoint_pb2.COMMITTED,)
+
+    @staticmethod
+    def _check_rejection(response, transaction=None):
+        """
+        :param response: protobuf ToriiResponse
+        :param transaction: optional protobuf Transaction the response is about
+        :raise: TransactionRejected subclass if the response reports a failure
+        """
+        if response.tx_status in IrohaGrpc.REJECTION_STATUSES:
+            raise TransactionRejected.from_response(response, transaction)
+
+    @staticmethod
+    def _tx_status_tuple(response, raise_on_rejection=False, transaction=None):
+        """
+        :param response: protobuf ToriiResponse
+        :param raise_on_rejection: raise TransactionRejected if the response reports a failure
+        :param transaction: optional protobuf Transaction the response is about
+        :return: a tuple with the symbolic status description,
+        integral status code, and error code (will be 0 if no error occurred)
+        """
+        if raise_on_rejection:
+            IrohaGrpc._check_rejection(response, transaction)
+        return IrohaGrpc._parse_tx_status(response)
+
+    def blocks_stream_queue(self, query, timeout=None, maxsize=0, loop=None, metadata=None):
+        """
+        Send a query for blocks stream to Iroha and put the blocks into asyncio.Queue.
+        Has to be called from asyncio event loop thread unless the loop is passed explicitly
+        :param query: protobuf BlocksQuery
+        :param timeout: timeout for network I/O operations in seconds
+        :param maxsize: maximum size of the queue, 0 means unlimited
+        :param loop: asyncio event loop the queue belongs to
+        :param metadata: a list of (key, value) pairs sent as gRPC metadata in addition to the client ones
+        :return: StreamQueue with the blocks, then grpc.RpcError instance if the stream failed,
+        and None as the end of stream marker, its cancel() stops the stream
+        """
+        stream = self.send_blocks_stream_query(query, timeout, metadata)
+        return self._track(StreamQueue(stream, maxsize, loop))
+
+    def tx_status_queue(self, transaction, timeout=None, maxsize=0, loop=None, metadata=None):
+        """
+        Put statuses of a transaction from status stream into asyncio.Queue.
+        Has to be called from asyncio event loop thread unless the loop is passed explicitly
+        :param transaction: the transaction, which status is about to be known
+        :param timeout: timeout for network I/O operations in seconds
+        :param maxsize: maximum size of the queue, 0 means unlimited
+        :param loop: asyncio event loop the queue belongs to
+        :param metadata: a list of (key, value) pairs sent as gRPC metadata in addition to the client ones
+        :return: StreamQueue with the status tuples (see tx_status_stream),
+        then grpc.RpcError instance if the stream failed, and None as the end of stream marker,
+        its cancel() stops the stream
+        """
+        stream = self.tx_status_stream(transaction, timeout, metadata=metadata)
+        return self._track(StreamQueue(stream, maxsize, loop))
 
     @staticmethod
     def _parse_tx_status(response):
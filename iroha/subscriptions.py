@@ -0,0 +1,149 @@
+#!/usr/bin/env python3
+#
+# Copyright Soramitsu Co., Ltd. All Rights Reserved.
+# SPDX-License-Identifier: Apache-2.0
+#
+
+import threading
+
+
+class SubscriptionGroup(object):
+    """
+    Owns a set of listeners (blocks streams, status streams, status polling),
+    each of them is run in its own thread. The first error raised by any listener
+    stops the whole group and is re-raised from join(), so the listeners are always shut down together
+
+    Usage example:
+    with SubscriptionGroup() as group:
+        group.add(net.send_blocks_stream_query(blocks_query), on_block)
+        group.add_polling(lambda: net.tx_status(tx), on_status, interval=1)
+        group.join()
+    """
+
+    def __init__(self, shutdown_timeout=5):
+        """
+        Create an empty group
+        :param shutdown_timeout: maximum time in seconds to wait for each listener thread
+        when leaving the context manager, listeners blocked on a stream without cancel() may outlive it
+        """
+        self._shutdown_timeout = shutdown_timeout
+        self._stop_event = threading.Event()
+        self._lock = threading.Lock()
+        self._threads = []
+        self._sources = []
+        self._error = None
+        self._running = 0
+        self._finished = threading.Event()
+        self._finished.set()
+
+    @property
+    def stopped(self):
+        """Whether the group was stopped explicitly or due to an error"""
+        return self._stop_event.is_set()
+
+    @property
+    def error(self):
+        """The first error raised by a listener or None"""
+        return self._error
+
+    def _start(self, target):
+        thread = threading.Thread(target=self._guard, args=(target,), daemon=True)
+        with self._lock:
+            self._threads.append(thread)
+            self._running += 1
+            self._finished.clear()
+        thread.start()
+
+    def _guard(self, target):
+        try:
+            target()
+        except Exception as e:
+            with self._lock:
+                if self._error is None and not self._stop_event.is_set():
+                    self._error = e
+                    self._finished.set()
+            self.stop(wait=False)
+        finally:
+            with self._lock:
+                self._running -= 1
+                if not self._running:
+                    self._finished.set()
+
+    def add(self, stream, handler):
+        """
+        Start a listener calling the handler for every item of a stream
+        :param stream: an iterable, e.g. the result of IrohaGrpc.send_blocks_stream_query,
+        if it has cancel() method it is called on the group shutdown to interrupt waiting for the next item
+        :param handler: callable accepting a single item
+        :return: None
+        """
+        assert not self.stopped, "The group is already stopped"
+        with self._lock:
+            self._sources.append(stream)
+
+        def listen():
+            for item in stream:
+                if self._stop_event.is_set():
+                    return
+                handler(item)
+
+        self._start(listen)
+
+    def add_polling(self, poll, handler, interval):
+        """
+        Start a listener calling the poll function periodically and passing its result to the handler
+        :param poll: callable without arguments, e.g. lambda: net.tx_status(tx)
+        :param handler: callable accepting the poll result
+        :param interval: delay between the calls in seconds
+        :return: None
+        """
+        assert not self.stopped, "The group is already stopped"
+
+        def listen():
+            while not self._stop_event.is_set():
+                handler(poll())
+                self._stop_event.wait(interval)
+
+        self._start(listen)
+
+    def stop(self, wait=True, timeout=None):
+        """
+        Stop all the listeners
+        :param wait: whether to wait for the listeners threads to finish
+        :param timeout: maximum time to wait for each thread in seconds
+        :return: None
+        """
+        self._stop_event.set()
+        with self._lock:
+            sources = list(self._sources)
+            threads = list(self._threads)
+        for source in sources:
+            cancel = getattr(source, 'cancel', None)
+            if callable(cancel):
+                cancel()
+        if wait:
+            current = threading.current_thread()
+            for thread in threads:
+                if thread is not current:
+                    thread.join(timeout)
+
+    def join(self, timeout=None):
+        """
+        Wait until all the listeners are finished or any of them fails
+        :param timeout: maximum time to wait in seconds
+        :return: bool, whether the waiting was not interrupted by the timeout
+        :raise: the first error raised by a listener
+        """
+        finished = self._finished.wait(timeout)
+        if self._error is not None:
+            raise self._error
+        return finished
+
+    def __enter__(self):
+        return self
+
+    def __exit__(self, exc_type, exc_value, traceback):
+        self.stop(timeout=self._shutdown_timeout)
+        if exc_type is None and self._error is not None:
+            raise self._error
+        return False
@@ -0,0 +1,490 @@
+#!/usr/bin/env python3
+#
+# Copyright Soramitsu Co., Ltd. All Rights Reserved.
+# SPDX-License-Identifier: Apache-2.0
+#
+
+import asyncio
+import binascii
+import collections
+import json
+import threading
+
+import grpc
+from google.protobuf import empty_pb2
+
+from .iroha import Iroha, IrohaCrypto, IrohaGrpc
+from . import endpoint_pb2
+from . import qry_responses_pb2
+
+
+class MockIrohaGrpc(IrohaGrpc):
+    """
+    In-memory replacement for IrohaGrpc to be used in unit tests.
+    Records everything that is sent and serves prepared responses,
+    so no running Iroha peer is needed.
+    Only the grpc channel is replaced, so the client logic is the same as of IrohaGrpc
+    """
+
+    COMMITTED_STATUSES = ('ENOUGH_SIGNATURES_COLLECTED',
+                          'STATEFUL_VALIDATION_SUCCESS',
+                          'COMMITTED')
+
+    def __init__(self, address=None, timeout=None, secure=False, *, max_message_length=None,
+                 channel=None, retry_policy=None, proxy=None, metadata=None):
+        """
+        Create mock Iroha client. Arguments are accepted for compatibility
+        with IrohaGrpc, only timeout, retry_policy and metadata are taken into account
+        """
+        self.transactions = []
+        self.queries = []
+        self.blocks_queries = []
+        self._query_handlers = {}
+        self._tx_statuses = {}
+        self._blocks = []
+        self._failing_methods = {}
+        super().__init__(address, timeout, channel=_MockChannel(self), retry_policy=retry_policy,
+                         metadata=metadata)
+
+    @staticmethod
+    def _hex_hash(transaction_or_hash):
+        """
+        Normalize a transaction or its hash to a hex string
+        :param transaction_or_hash: protobuf Transaction, raw bytes hash or hex string hash
+        :return: hex encoded hash as str
+        """
+        if isinstance(transaction_or_hash, str):
+            return transaction_or_hash
+        if isinstance(transaction_or_hash, bytes):
+            return binascii.hexlify(transaction_or_hash).decode('utf-8')
+        return binascii.hexlify(IrohaCrypto.hash(transaction_or_hash)).decode('utf-8')
+
+    def set_query_response(self, name, response):
+        """
+        Set a response to be returned for queries of a certain type
+        :param name: CamelCased name of the query, e.g. 'GetAccountAssets'
+        :param response: protobuf QueryResponse or a callable accepting
+        the protobuf Query and returning a QueryResponse (useful for stateful mocks)
+        :return: None
+        """
+        self._query_handlers[Iroha._camel_case_to_snake_case(name)] = response
+
+    def set_tx_statuses(self, transaction, statuses):
+        """
+        Set the sequence of statuses reported for a transaction
+        :param transaction: protobuf Transaction or its hash (bytes or hex string)
+        :param statuses: list of symbolic statuses, e.g. ['STATELESS_VALIDATION_FAILED'],
+        or protobuf ToriiResponse messages
+        :return: None
+        """
+        self._tx_statuses[self._hex_hash(transaction)] = list(statuses)
+
+    def add_block(self, block_response):
+        """
+        Add a block to be yielded by blocks stream queries
+        :param block_response: protobuf BlockQueryResponse
+        :return: None
+        """
+        self._blocks.append(block_response)
+
+    def set_rpc_error(self, method, code, details=''):
+        """
+        Make all calls of a grpc method fail
+        :param method: name of the method: Torii, ListTorii, Status, StatusStream, Find or FetchCommits
+        :param code: grpc.StatusCode to fail with, None to stop failing
+        :param details: error details
+        :return: None
+        """
+        if code is None:
+            self._failing_methods.pop(method, None)
+        else:
+            self._failing_methods[method] = MockRpcError(code, details)
+
+    def _accept(self, transaction):
+        self.transactions.append(transaction)
+        tx_hash = self._hex_hash(transaction)
+        if tx_hash not in self._tx_statuses:
+            self._tx_statuses[tx_hash] = list(self.COMMITTED_STATUSES)
+
+    def _statuses(self, tx_hash):
+        for status in self._tx_statuses.get(tx_hash, ['NOT_RECEIVED']):
+            if isinstance(status, str):
+                status = endpoint_pb2.ToriiResponse(
+                    tx_status=endpoint_pb2.TxStatus.Value(status),
+                    tx_hash=tx_hash)
+            yield status
+
+    def _find(self, query):
+        self.queries.append(query)
+        handler = self._query_handlers.get(query.payload.WhichOneof('query'))
+        if handler is None:
+            response = qry_responses_pb2.QueryResponse()
+            response.error_response.reason = qry_responses_pb2.ErrorResponse.NOT_SUPPORTED
+            response.error_response.message = 'no mock response is set for the query'
+        elif callable(handler):
+            response = handler(query)
+        else:
+            response = qry_responses_pb2.QueryResponse()
+            response.CopyFrom(handler)
+        response.query_hash = binascii.hexlify(IrohaCrypto.hash(query))
+        return response
+
+    def _fetch_commits(self, query):
+        self.blocks_queries.append(query)
+        return iter(list(self._blocks))
+
+    def async_client(self, timeout=None):
+        """
+        Create IrohaGrpcAsync sharing the state of this mock
+        :param timeout: timeout for network I/O operations in seconds
+        :return: iroha.aio.IrohaGrpcAsync
+        """
+        from .aio import IrohaGrpcAsync
+        return IrohaGrpcAsync(timeout=timeout, channel=_AsyncMockChannel(self),
+                              retry_policy=self._retry_policy, metadata=self._metadata)
+
+    def _call(self, method, request):
+        """
+        Serve a grpc call
+        :param method: short name of the method, e.g. Torii
+        :param request: protobuf request message
+        :return: protobuf response message or an iterator over them for streams
+        """
+        if method in self._failing_methods:
+            raise self._failing_methods[method]
+        if method == 'Torii':
+            self._accept(request)
+            return empty_pb2.Empty()
+        if method == 'ListTorii':
+            for transaction in request.transactions:
+                self._accept(transaction)
+            return empty_pb2.Empty()
+        if method == 'Status':
+            return list(self._statuses(request.tx_hash))[-1]
+        if method == 'StatusStream':
+            return self._statuses(request.tx_hash)
+        if method == 'Find':
+            return self._find(request)
+        if method == 'FetchCommits':
+            return self._fetch_commits(request)
+        raise MockRpcError(grpc.StatusCode.UNIMPLEMENTED, 'Unknown method {}'.format(method))
+
+
+class _MockChannel(object):
+    """
+    Python-implemented grpc.Channel passing request messages to MockIrohaGrpc as they are
+    """
+
+    def __init__(self, mock):
+        self._mock = mock
+
+    def _callable(self, method):
+        name = method.rsplit('/', 1)[-1]
+
+        def call(request, timeout=None, **kwargs):
+            return self._mock._call(name, request)
+
+        return call
+
+    def unary_unary(self, method, request_serializer=None, response_deserializer=None):
+        return self._callable(method)
+
+    def unary_stream(self, method, request_serializer=None, response_deserializer=None):
+        return self._callable(method)
+
+    def close(self):
+        pass
+
+
+class _AsyncMockChannel(object):
+    """
+    Python-implemented grpc.aio.Channel passing request messages to MockIrohaGrpc as they are
+    """
+
+    def __init__(self, mock):
+        self._mock = mock
+
+    def unary_unary(self, method, request_serializer=None, response_deserializer=None):
+        name = method.rsplit('/', 1)[-1]
+
+        async def call(request, timeout=None, **kwargs):
+            return self._mock._call(name, request)
+
+        return call
+
+    def unary_stream(self, method, request_serializer=None, response_deserializer=None):
+        name = method.rsplit('/', 1)[-1]
+
+        def call(request, timeout=None, **kwargs):
+            return _AsyncMockCall(lambda: self._mock._call(name, request))
+
+        return call
+
+    async def close(self):
+        pass
+
+
+class _AsyncMockCall(object):
+    """
+    Python-implemented grpc.aio.UnaryStreamCall, cancel() interrupts the pending read
+    with asyncio.CancelledError as the grpc one does
+    """
+
+    def __init__(self, responses):
+        """
+        :param responses: callable returning an iterable or an asynchronous iterable
+        of the responses, it is called on the first read
+        """
+        self._responses = responses
+        self._iterator = None
+        self._pending = None
+        self._cancelled = False
+
+    def cancel(self):
+        """
+        :return: whether the call was cancelled by this invocation
+        """
+        if self._cancelled:
+            return False
+        self._cancelled = True
+        if self._pending is not None:
+            self._pending.cancel()
+        return True
+
+    def cancelled(self):
+        return self._cancelled
+
+    def __aiter__(self):
+        return self
+
+    async def _read(self):
+        if self._iterator is None:
+            responses = self._responses()
+            self._iterator = responses.__aiter__() if hasattr(responses, '__aiter__') \
+                else iter(responses)
+        if hasattr(self._iterator, '__anext__'):
+            return await self._iterator.__anext__()
+        try:
+            return next(self._iterator)
+        except StopIteration:
+            raise StopAsyncIteration
+
+    async def __anext__(self):
+        if self._cancelled:
+            raise asyncio.CancelledError()
+        self._pending = asyncio.ensure_future(self._read())
+        try:
+            return await self._pending
+        finally:
+            self._pending = None
+
+
+class MockRpcError(grpc.RpcError):
+    """
+    grpc error raised by MockIrohaGrpc and ReplayChannel
+    """
+
+    def __init__(self, code, details):
+        super().__init__(details)
+        self._code = code
+        self._details = details
+
+    def code(self):
+        return self._code
+
+    def details(self):
+        return self._details
+
+
+class RecordingChannel(object):
+    """
+    Wrapper of grpc.Channel which writes all the requests and responses
+    (including every message of response streams) to a file.
+    The file can be served later with ReplayChannel.
+
+    Usage example:
+    net = IrohaGrpc(channel=RecordingChannel(grpc.insecure_channel('127.0.0.1:50051'), 'session.jsonl'))
+    """
+
+    def __init__(self, channel, path):
+        """
+        :param channel: grpc.Channel to send requests through
+        :param path: path of a file to append the records to
+        """
+        self._channel = channel
+        self._path = path
+        self._lock = threading.Lock()
+
+    def _write(self, record):
+        with self._lock:
+            with open(self._path, 'a') as records_file:
+                records_file.write(json.dumps(record) + '\n')
+
+    @staticmethod
+    def _error(error):
+        code = error.code() if hasattr(error, 'code') else grpc.StatusCode.UNKNOWN
+        details = error.details() if hasattr(error, 'details') else str(error)
+        return {'code': code.name, 'details': details}
+
+    def unary_unary(self, method, request_serializer=None, response_deserializer=None):
+        # raw bytes go through the wrapped channel, so that they can be recorded
+        inner = self._channel.unary_unary(method)
+
+        def call(request, timeout=None, **kwargs):
+            request_bytes = request_serializer(request) if request_serializer else request
+            record = {'method': method, 'request': request_bytes.hex(), 'responses': []}
+            try:
+                response = inner(request_bytes, timeout=timeout, **kwargs)
+            except grpc.RpcError as e:
+                record['error'] = self._error(e)
+                self._write(record)
+                raise
+            record['responses'].append(response.hex())
+            self._write(record)
+            return response_deserializer(response) if response_deserializer else response
+
+        return call
+
+    def unary_stream(self, method, request_serializer=None, response_deserializer=None):
+        inner = self._channel.unary_stream(method)
+
+        def call(request, timeout=None, **kwargs):
+            request_bytes = request_serializer(request) if request_serializer else request
+            record = {'method': method, 'request': request_bytes.hex(), 'responses': []}
+            try:
+                responses = inner(request_bytes, timeout=timeout, **kwargs)
+            except grpc.RpcError as e:
+                record['error'] = self._error(e)
+                self._write(record)
+                raise
+            return _RecordingStream(self, responses, record, response_deserializer)
+
+        return call
+
+    def close(self):
+        self._channel.close()
+
+
+class _RecordingStream(object):
+    """
+    Iterator over a response stream passing through RecordingChannel,
+    cancel() is forwarded to the wrapped call. The record is written once the stream
+    is exhausted, fails, or is cancelled
+    """
+
+    def __init__(self, channel, call, record, response_deserializer):
+        self._channel = channel
+        self._call = call
+        self._responses = iter(call)
+        self._record = record
+        self._response_deserializer = response_deserializer
+        self._lock = threading.Lock()
+        self._written = False
+
+    def _finish(self, error=None):
+        with self._lock:
+            if self._written:
+                return
+            self._written = True
+        if error is not None:
+            self._record['error'] = RecordingChannel._error(error)
+        self._channel._write(self._record)
+
+    def __iter__(self):
+        return self
+
+    def __next__(self):
+        try:
+            response = next(self._responses)
+        except StopIteration:
+            self._finish()
+            raise
+        except grpc.RpcError as e:
+            self._finish(e)
+            raise
+        with self._lock:
+            if not self._written:
+                self._record['responses'].append(response.hex())
+        return self._response_deserializer(response) if self._response_deserializer else response
+
+    def cancel(self):
+        """
+        Write the messages received so far and cancel the wrapped call
+        :return: None
+        """
+        self._finish()
+        cancel = getattr(self._call, 'cancel', None)
+        if callable(cancel):
+            cancel()
+
+    def __del__(self):
+        # the attributes are missing if __init__ failed, e.g. iter(call) raised
+        if hasattr(self, '_written'):
+            self._finish()
+
+
+class ReplayChannel(object):
+    """
+    Python-implemented grpc.Channel serving responses recorded by RecordingChannel,
+    so a session can be reproduced without a running Iroha peer.
+    Calls of every method are answered in the order they were recorded.
+
+    Usage example:
+    net = IrohaGrpc(channel=ReplayChannel('session.jsonl'))
+    """
+
+    def __init__(self, path, strict=False):
+        """
+        :param path: path of a file written by RecordingChannel
+        :param strict: require requests to be byte-to-byte equal to recorded ones,
+        disabled by default since transactions and queries usually contain creation time
+        """
+        self._strict = strict
+        self._lock = threading.Lock()
+        self._records = collections.defaultdict(collections.deque)
+        with open(path, 'r') as records_file:
+            for line in records_file:
+                if line.strip():
+                    record = json.loads(line)
+                    self._records[record['method']].append(record)
+
+    def _next_record(self, method, request_bytes):
+        with self._lock:
+            if not self._records[method]:
+                raise MockRpcError(grpc.StatusCode.UNAVAILABLE,
+                                   'No more recorded calls of {}'.format(method))
+            record = self._records[method].popleft()
+        if self._strict and bytes.fromhex(record['request']) != request_bytes:
+            raise MockRpcError(grpc.StatusCode.INVALID_ARGUMENT,
+                               'Request to {} differs from the recorded one'.format(method))
+        return record
+
+    @staticmethod
+    def _raise_recorded_error(record):
+        if 'error' in record:
+            error = record['error']
+            raise MockRpcError(grpc.StatusCode[error['code']], error['details'])
+
+    def unary_unary(self, method, request_serializer=None, response_deserializer=None):
+        def call(request, timeout=None, **kwargs):
+            request_bytes = request_serializer(request) if request_serializer else request
+            record = self._next_record(method, request_bytes)
+            self._raise_recorded_error(record)
+            response = bytes.fromhex(record['responses'][0])
+            return response_deserializer(response) if response_deserializer else response
+
+        return call
+
+    def unary_stream(self, method, request_serializer=None, response_deserializer=None):
+        def call(request, timeout=None, **kwargs):
+            request_bytes = request_serializer(request) if request_serializer else request
+            record = self._next_record(method, request_bytes)
+            for response in record['responses']:
+                response = bytes.fromhex(response)
+                yield response_deserializer(response) if response_deserializer else response
+            self._raise_recorded_error(record)
+
+        return call
+
+    def close(self):
+        pass
@@ -27,3 +27,13 @@ data_ids = ['priv_key, pub_key({},{})'.format(t.private_key, t.public_key)
 def crypto_data(request):
     return request.param
 
+
+@pytest.fixture
+def private_key():
+    return 'f101537e319568c765b2cc89698325604991dca57b9716b58016b253506cab70'
+
+
+@pytest.fixture(name='iroha')
+def admin_iroha():
+    return Iroha('admin@test')
+
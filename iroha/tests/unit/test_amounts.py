@@ -0,0 +1,41 @@
+"""Test to check decimal amounts in commands"""
+
+from decimal import Decimal
+
+import pytest
+
+from iroha import Iroha, SerializationError
+
+
+def test_decimal_amount_is_exact():
+    command = Iroha.command('AddAssetQuantity', asset_id='coin#test', amount=Decimal('13.37'))
+    assert command.add_asset_quantity.amount == '13.37'
+
+
+def test_decimal_amount_in_positional_notation():
+    command = Iroha.command('TransferAsset', src_account_id='a@test', dest_account_id='b@test',
+                            asset_id='coin#test', description='', amount=Decimal('1E+3'))
+    assert command.transfer_asset.amount == '1000'
+
+
+def test_amount_limits_are_accepted():
+    digits = str(Iroha.AMOUNT_MAX_MANTISSA)
+    largest = '{}.{}'.format(digits[:-2], digits[-2:])
+    command = Iroha.command('AddAssetQuantity', asset_id='coin#test', amount=Decimal(largest))
+    assert command.add_asset_quantity.amount == largest
+    command = Iroha.command('AddAssetQuantity', asset_id='coin#test', amount=Decimal('1E-255'))
+    assert command.add_asset_quantity.amount == '0.' + '0' * 254 + '1'
+
+
+@pytest.mark.parametrize('amount', [
+    Decimal('-1'),
+    Decimal('NaN'),
+    Decimal('Infinity'),
+    Decimal('1E-256'),
+    Decimal(Iroha.AMOUNT_MAX_MANTISSA + 1),
+    Decimal('1E+100000000'),
+    Decimal('0.' + '1' * 100),
+], ids=['negative', 'nan', 'infinity', 'scale', 'mantissa', 'exponent', 'digits'])
+def test_unrepresentable_amounts(amount):
+    with pytest.raises(SerializationError):
+        Iroha.command('SubtractAssetQuantity', asset_id='coin#test', amount=amount)
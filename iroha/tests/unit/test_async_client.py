@@ -0,0 +1,144 @@
+"""Test to check the asyncio Iroha client"""
+
+import asyncio
+
+import grpc
+import pytest
+
+from iroha import IrohaCrypto, IrohaRpcError, QueryError
+from iroha.aio import AsyncIrohaStream
+from iroha.endpoint_pb2 import ToriiResponse, ENOUGH_SIGNATURES_COLLECTED
+from iroha.qry_responses_pb2 import QueryResponse
+from iroha.testing import MockIrohaGrpc
+
+
+@pytest.fixture
+def tx(iroha, private_key):
+    return IrohaCrypto.sign_transaction(
+        iroha.transaction([iroha.command('CreateDomain', domain_id='domain', default_role='user')]),
+        private_key)
+
+
+def test_submit_and_status_stream(tx):
+    mock = MockIrohaGrpc()
+
+    async def run():
+        async with mock.async_client() as net:
+            await net.send_tx(tx)
+            assert (await net.tx_status(tx))[0] == 'COMMITTED'
+            return [status async for status, _, _ in net.tx_status_stream(tx)]
+
+    assert asyncio.run(run()) == list(MockIrohaGrpc.COMMITTED_STATUSES)
+    assert mock.transactions == [tx]
+
+
+def test_queries_and_errors(iroha, tx):
+    mock = MockIrohaGrpc()
+    canned = QueryResponse()
+    canned.asset_response.asset.asset_id = 'coin#domain'
+    mock.set_query_response('GetAssetInfo', canned)
+    net = mock.async_client()
+
+    async def query(name, **kwargs):
+        return await net.send_query(iroha.query(name, **kwargs), raise_on_error=True)
+
+    assert asyncio.run(query('GetAssetInfo', asset_id='coin#domain')) \
+        .asset_response.asset.asset_id == 'coin#domain'
+    with pytest.raises(QueryError):
+        asyncio.run(query('GetRoles'))
+
+    mock.set_rpc_error('Torii', grpc.StatusCode.INTERNAL)
+    with pytest.raises(IrohaRpcError):
+        asyncio.run(net.send_tx(tx))
+
+
+def test_send_tx_blocking(tx):
+    mock = MockIrohaGrpc()
+    net = mock.async_client(timeout=5)
+    assert asyncio.run(net.send_tx_blocking(tx))[0] == 'COMMITTED'
+
+
+def test_stream_cancellation():
+    class Call(object):
+        cancelled = False
+
+        def cancel(self):
+            self.cancelled = True
+
+    async def items():
+        yield 1
+        await asyncio.Event().wait()
+        yield 2
+
+    async def run():
+        call = Call()
+        stream = AsyncIrohaStream(call, items())
+        received = []
+
+        async def consume():
+            async for item in stream:
+                received.append(item)
+
+        task = asyncio.ensure_future(consume())
+        await asyncio.sleep(0.01)
+        task.cancel()
+        with pytest.raises(asyncio.CancelledError):
+            await task
+        assert call.cancelled and stream.cancelled
+        assert received == [1]
+        await stream.aclose()
+
+    asyncio.run(run())
+
+
+def test_cancel_stops_call_iteration(tx):
+    class HangingPeer(MockIrohaGrpc):
+        """Status stream reports one status and never ends"""
+
+        def _call(self, method, request):
+            if method != 'StatusStream':
+                return super()._call(method, request)
+
+            async def statuses():
+                yield ToriiResponse(tx_status=ENOUGH_SIGNATURES_COLLECTED)
+                await asyncio.Event().wait()
+
+            return statuses()
+
+    async def run():
+        async with HangingPeer().async_client() as net:
+            stream = net.tx_status_stream(tx)
+            received = []
+
+            async def consume():
+                async for status in stream:
+                    received.append(status[0])
+
+            task = asyncio.ensure_future(consume())
+            while not received:
+                await asyncio.sleep(0.01)
+            # the pending read of the call fails with CancelledError, the task is not cancelled
+            stream.cancel()
+            await asyncio.wait_for(task, 5)
+            assert not task.cancelled()
+            assert received == ['ENOUGH_SIGNATURES_COLLECTED']
+
+    asyncio.run(asyncio.wait_for(run(), 5))
+
+
+def test_query_iter_and_get_transaction(iroha, private_key, tx):
+    mock = MockIrohaGrpc()
+    assets = QueryResponse()
+    assets.account_assets_response.account_assets.add(asset_id='coin#test', balance='1')
+    mock.set_query_response('GetAccountAssets', assets)
+    found = QueryResponse()
+    found.transactions_response.transactions.extend([tx])
+    mock.set_query_response('GetTransactions', found)
+    net = mock.async_client()
+
+    async def run():
+        asset_ids = [asset.asset_id async for asset in net.query_iter(
+            iroha, private_key, 'GetAccountAssets', account_id='alice@test')]
+        return asset_ids, await net.get_transaction(iroha, private_key, IrohaCrypto.hash(tx))
+
+    assert asyncio.run(run()) == (['coin#test'], tx)
@@ -0,0 +1,65 @@
+"""Test to check deposit tracking"""
+
+import json
+
+from iroha.block_pb2 import Block
+from iroha.deposits import DepositTracker
+
+
+def make_block(iroha, height, *amounts):
+    block = Block()
+    block.block_v1.payload.height = height
+    block.block_v1.payload.transactions.extend([iroha.transaction([
+        iroha.command('TransferAsset', src_account_id='admin@test', dest_account_id='alice@test',
+                      asset_id='coin#test', description='', amount=amount)
+        for amount in amounts], created_time=height)])
+    return block
+
+
+def test_deposits_are_delivered_once(iroha, tmp_path):
+    checkpoint_path = str(tmp_path / 'checkpoint.json')
+    delivered = []
+    failing = {'raise': True}
+
+    def callback(transfer):
+        if transfer.amount == '2.00' and failing['raise']:
+            failing['raise'] = False
+            raise RuntimeError('temporary failure')
+        delivered.append(transfer.amount)
+
+    tracker = DepositTracker(None, ['alice@test'], callback, checkpoint_path)
+    tracker.process_block(make_block(iroha, 1, '1.00'))
+    try:
+        tracker.process_block(make_block(iroha, 2, '1.50', '2.00'))
+    except RuntimeError:
+        pass
+    assert delivered == ['1.00', '1.50']
+
+    resumed = DepositTracker(None, ['alice@test'], callback, checkpoint_path)
+    assert resumed.last_height == 1
+    resumed.process_block(make_block(iroha, 2, '1.50', '2.00'))
+    assert delivered == ['1.00', '1.50', '2.00']
+    assert resumed.last_height == 2
+
+
+def test_blocks_without_deposits_are_saved_in_batches(iroha, tmp_path):
+    checkpoint_path = tmp_path / 'checkpoint.json'
+    tracker = DepositTracker(None, ['bob@test'], lambda transfer: None, str(checkpoint_path),
+                             checkpoint_interval=3)
+    tracker.process_block(make_block(iroha, 1, '1.00'))
+    tracker.process_block(make_block(iroha, 2, '1.00'))
+    assert not checkpoint_path.exists()
+    tracker.process_block(make_block(iroha, 3, '1.00'))
+    assert json.loads(checkpoint_path.read_text())['height'] == 3
+    tracker.process_block(make_block(iroha, 4, '1.00'))
+    assert json.loads(checkpoint_path.read_text())['height'] == 3
+    tracker.flush()
+    assert json.loads(checkpoint_path.read_text())['height'] == 4
+
+
+def test_other_accounts_are_ignored(iroha):
+    delivered = []
+    tracker = DepositTracker(None, ['bob@test'], delivered.append)
+    tracker.process_block(make_block(iroha, 1, '1.00'))
+    assert delivered == []
+    assert tracker.last_height == 1
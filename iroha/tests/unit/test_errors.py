@@ -0,0 +1,181 @@
+"""Test to check exceptions raised by the library"""
+
+import grpc
+import pytest
+
+from iroha import (Iroha, IrohaCrypto, ErrorKind, IrohaError, ConfigError, SerializationError,
+                   IrohaRpcError, IrohaConnectionError, QueryError, TransactionRejected,
+                   StatelessValidationFailed, StatefulValidationFailed, MstExpired, BadRequest,
+                   Unauthorized, NotFound, TooManyRequests, PeerUnavailable, IrohaTimeoutError)
+from iroha.endpoint_pb2 import ToriiResponse, STATELESS_VALIDATION_FAILED, STATEFUL_VALIDATION_FAILED
+from iroha.testing import MockIrohaGrpc
+
+
+@pytest.fixture
+def tx(iroha):
+    return iroha.transaction([iroha.command('CreateDomain', domain_id='domain', default_role='user')])
+
+
+def test_serialization_errors(iroha):
+    with pytest.raises(SerializationError):
+        Iroha.command('CreateEverything')
+    with pytest.raises(SerializationError):
+        Iroha.command('CreateDomain', domain='domain')
+    with pytest.raises(SerializationError):
+        iroha.query('GetBlock', height='first')
+
+
+def test_serialization_errors_keep_original_types(iroha):
+    with pytest.raises(AttributeError):
+        Iroha.command('CreateDomain', domain='domain')
+    with pytest.raises(TypeError):
+        iroha.query('GetBlock', height='first')
+    with pytest.raises(TypeError):
+        iroha.transaction([Iroha.command('CreateDomain', domain_id='domain', default_role='user')],
+                          created_time='yesterday')
+
+
+def test_config_error():
+    with pytest.raises(ConfigError):
+        Iroha('admin@test', naive_datetime='sometimes')
+
+
+def test_rpc_errors_stay_grpc_errors(tx):
+    net = MockIrohaGrpc()
+    net.set_rpc_error('Torii', grpc.StatusCode.UNAVAILABLE, 'peer is down')
+    with pytest.raises(IrohaConnectionError) as error:
+        net.send_tx(tx)
+    assert isinstance(error.value, grpc.RpcError)
+    assert isinstance(error.value, ConnectionError)
+    assert error.value.code() == grpc.StatusCode.UNAVAILABLE
+    assert error.value.details() == 'peer is down'
+
+    net.set_rpc_error('StatusStream', grpc.StatusCode.INTERNAL)
+    with pytest.raises(IrohaRpcError):
+        list(net.tx_status_stream(tx))
+
+
+def test_query_error(iroha):
+    net = MockIrohaGrpc()
+    query = iroha.query('GetRoles')
+    assert net.send_query(query).HasField('error_response')
+    with pytest.raises(QueryError) as error:
+        net.send_query(query, raise_on_error=True)
+    assert error.value.reason == 'NOT_SUPPORTED'
+    assert isinstance(error.value, IrohaError)
+
+
+def test_transaction_rejected(tx):
+    net = MockIrohaGrpc()
+    net.set_tx_statuses(tx, ['STATELESS_VALIDATION_SUCCESS', 'REJECTED'])
+    assert net.tx_status(tx)[0] == 'REJECTED'
+    with pytest.raises(TransactionRejected) as error:
+        list(net.tx_status_stream(tx, raise_on_rejection=True))
+    assert error.value.status_name == 'REJECTED'
+
+
+def test_rejection_reasons(tx):
+    net = MockIrohaGrpc()
+    net.set_tx_statuses(tx, [ToriiResponse(tx_status=STATELESS_VALIDATION_FAILED,
+                                           err_or_cmd_name='bad signature')])
+    with pytest.raises(StatelessValidationFailed) as error:
+        net.tx_status(tx, raise_on_rejection=True)
+    assert error.value.reason == 'bad signature'
+    assert error.value.data['reason'] == 'bad signature'
+
+    net.set_tx_statuses(tx, [ToriiResponse(tx_status=STATEFUL_VALIDATION_FAILED,
+                                           err_or_cmd_name='CreateDomain',
+                                           failed_cmd_index=0, error_code=3)])
+    with pytest.raises(StatefulValidationFailed) as error:
+        list(net.tx_status_stream(tx, raise_on_rejection=True))
+    assert error.value.command_name == 'CreateDomain'
+    assert error.value.command_index == 0
+    assert error.value.error_code == 3
+    assert error.value.command == tx.payload.reduced_payload.commands[0]
+
+    net.set_tx_statuses(tx, ['MST_EXPIRED'])
+    with pytest.raises(MstExpired):
+        net.tx_status(tx, raise_on_rejection=True)
+
+
+def test_error_kinds_and_data(iroha):
+    net = MockIrohaGrpc()
+    net.set_rpc_error('Find', grpc.StatusCode.PERMISSION_DENIED, 'denied')
+    query = iroha.query('GetRoles')
+    with pytest.raises(IrohaError) as error:
+        net.send_query(query)
+    assert isinstance(error.value, Unauthorized)
+    assert error.value.kind == ErrorKind.UNAUTHORIZED
+    assert error.value.data == {'grpc_code': 'PERMISSION_DENIED', 'grpc_details': 'denied',
+                                'query_hash': IrohaCrypto.hash(query).hex()}
+
+    with pytest.raises(IrohaError) as error:
+        Iroha.command('CreateEverything')
+    assert error.value.kind == ErrorKind.SERIALIZATION
+    assert error.value.data == {'command': 'CreateEverything'}
+
+
+@pytest.mark.parametrize('code, error_class', [
+    (grpc.StatusCode.INVALID_ARGUMENT, BadRequest),
+    (grpc.StatusCode.UNAUTHENTICATED, Unauthorized),
+    (grpc.StatusCode.NOT_FOUND, NotFound),
+    (grpc.StatusCode.RESOURCE_EXHAUSTED, TooManyRequests),
+    (grpc.StatusCode.UNAVAILABLE, PeerUnavailable),
+    (grpc.StatusCode.INTERNAL, IrohaRpcError),
+])
+def test_status_codes_classification(code, error_class, tx):
+    net = MockIrohaGrpc()
+    net.set_rpc_error('ListTorii', code, 'body')
+    with pytest.raises(error_class) as error:
+        net.send_txs([tx])
+    assert error.value.details() == 'body'
+
+
+def test_timeout_error(iroha, tx):
+    net = MockIrohaGrpc(timeout=3)
+    net.set_rpc_error('Torii', grpc.StatusCode.DEADLINE_EXCEEDED)
+    with pytest.raises(TimeoutError) as error:
+        net.send_tx(tx)
+    assert isinstance(error.value, IrohaTimeoutError)
+    assert error.value.deadline == 3
+    assert error.value.elapsed >= 0
+    assert error.value.may_be_in_flight
+
+    net.set_rpc_error('Find', grpc.StatusCode.DEADLINE_EXCEEDED)
+    with pytest.raises(IrohaTimeoutError) as error:
+        net.send_query(iroha.query('GetRoles'), timeout=1)
+    assert error.value.deadline == 1
+    assert not error.value.may_be_in_flight
+
+
+def test_blocking_timeout_reports_overall_timeout(tx):
+    net = MockIrohaGrpc()
+    net.set_rpc_error('StatusStream', grpc.StatusCode.DEADLINE_EXCEEDED)
+    with pytest.raises(IrohaTimeoutError) as error:
+        net.send_tx_blocking(tx, timeout=2)
+    assert error.value.deadline == 2
+    assert error.value.data['deadline'] == 2
+    assert 0 <= error.value.elapsed < 2
+
+
+def test_errors_carry_hashes(iroha, tx):
+    tx_hash = IrohaCrypto.hash(tx).hex()
+    net = MockIrohaGrpc()
+    net.set_rpc_error('Torii', grpc.StatusCode.INTERNAL)
+    with pytest.raises(IrohaRpcError) as error:
+        net.send_tx(tx)
+    assert error.value.tx_hash == tx_hash
+    assert error.value.data['tx_hashes'] == [tx_hash]
+    assert tx_hash in str(error.value)
+
+    other = iroha.transaction([iroha.command('CreateDomain', domain_id='other', default_role='user')])
+    net.set_rpc_error('ListTorii', grpc.StatusCode.DEADLINE_EXCEEDED)
+    with pytest.raises(IrohaTimeoutError) as error:
+        net.send_txs([tx, other])
+    assert error.value.tx_hashes == [tx_hash, IrohaCrypto.hash(other).hex()]
+    assert error.value.tx_hash is None
+
+    query = iroha.query('GetRoles')
+    with pytest.raises(QueryError) as error:
+        net.send_query(query, raise_on_error=True)
+    assert error.value.query_hash == IrohaCrypto.hash(query).hex()
@@ -0,0 +1,84 @@
+"""Test to check ledger iterators of the explorer"""
+
+import pytest
+
+from iroha import Iroha, BlocksQueryError, ErrorKind, QueryError
+from iroha.block_pb2 import Block
+from iroha.explorer import Explorer
+from iroha.qry_responses_pb2 import BlockQueryResponse, QueryResponse
+from iroha.testing import MockIrohaGrpc
+
+ledger = [
+    [Iroha.command('CreateAccount', account_name='alice', domain_id='test', public_key='00' * 32)],
+    [Iroha.command('TransferAsset', src_account_id='admin@test', dest_account_id='alice@test',
+                   asset_id='coin#test', description='top up', amount='1.00'),
+     Iroha.command('TransferAsset', src_account_id='admin@test', dest_account_id='bob@test',
+                   asset_id='gold#test', description='', amount='2.00')],
+]
+
+
+@pytest.fixture
+def net(iroha):
+    def get_block(query):
+        height = query.payload.get_block.height
+        response = QueryResponse()
+        if height > len(ledger):
+            response.error_response.error_code = 3
+            return response
+        block = Block()
+        block.block_v1.payload.height = height
+        block.block_v1.payload.transactions.extend([iroha.transaction(ledger[height - 1])])
+        response.block_response.block.CopyFrom(block)
+        return response
+
+    net = MockIrohaGrpc()
+    net.set_query_response('GetBlock', get_block)
+    return net
+
+
+@pytest.fixture
+def make_explorer(net, iroha, private_key):
+    def make_explorer(*streamed_heights):
+        for height in streamed_heights:
+            response = BlockQueryResponse()
+            response.block_response.block.block_v1.payload.height = height
+            net.add_block(response)
+        return Explorer(net, iroha, private_key)
+    return make_explorer
+
+
+def test_blocks_stop_at_ledger_top(make_explorer):
+    heights = [block.block_v1.payload.height for block in make_explorer().blocks()]
+    assert heights == [1, 2]
+
+
+def test_listen_for_blocks(make_explorer):
+    explorer = make_explorer(3)
+    heights = [block.block_v1.payload.height for block in explorer.listen_for_blocks(from_height=2)]
+    assert heights == [2, 3]
+    assert [block.block_v1.payload.height for block in explorer.listen_for_blocks()] == [3]
+
+
+def test_blocks_stream_error(net, make_explorer):
+    response = BlockQueryResponse()
+    response.block_error_response.message = 'no permission'
+    net.add_block(response)
+    with pytest.raises(BlocksQueryError) as error:
+        list(make_explorer().listen_for_blocks())
+    assert isinstance(error.value, QueryError)
+    assert error.value.kind == ErrorKind.QUERY
+    assert error.value.message == 'no permission'
+    assert error.value.response.block_error_response.message == 'no permission'
+
+
+def test_transfers_by_asset(make_explorer):
+    transfers = list(make_explorer().transfers(asset_id='coin#test'))
+    assert len(transfers) == 1
+    assert transfers[0].height == 2
+    assert transfers[0].dest_account_id == 'alice@test'
+    assert transfers[0].amount == '1.00'
+
+
+def test_account_creations(make_explorer):
+    creations = list(make_explorer().account_creations())
+    assert [creation.account_id for creation in creations] == ['alice@test']
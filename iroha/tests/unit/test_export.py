@@ -0,0 +1,33 @@
+"""Test to check export and import of transactions and queries"""
+
+import pytest
+
+from iroha import Iroha, IrohaCrypto, SerializationError
+from iroha.queries_pb2 import Query
+
+
+def test_transaction_round_trip(iroha, private_key):
+    tx = iroha.transaction([iroha.command('CreateDomain', domain_id='domain', default_role='user')])
+    exported = Iroha.to_json(tx)
+    assert '"create_domain"' in exported
+    unsigned = Iroha.from_json(exported)
+    assert IrohaCrypto.hash(unsigned) == IrohaCrypto.hash(tx)
+
+    signed = IrohaCrypto.sign_transaction(unsigned, private_key)
+    assert Iroha.from_json(Iroha.to_json(signed)) == signed
+    assert Iroha.from_bytes(Iroha.to_bytes(signed)) == signed
+
+
+def test_query_round_trip(iroha, private_key):
+    query = IrohaCrypto.sign_query(iroha.query('GetAccount', account_id='alice@test'), private_key)
+    assert Iroha.from_json(Iroha.to_json(query), 'Query') == query
+    assert Iroha.from_bytes(Iroha.to_bytes(query), Query) == query
+
+
+def test_import_errors():
+    with pytest.raises(SerializationError):
+        Iroha.from_json('{"payload": {"unknown": 1}}')
+    with pytest.raises(SerializationError):
+        Iroha.from_bytes(b'\xff\xff', 'Query')
+    with pytest.raises(SerializationError):
+        Iroha.from_json('{}', 'Block')
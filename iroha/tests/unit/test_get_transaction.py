@@ -0,0 +1,28 @@
+"""Test to check fetching transactions by hash"""
+
+from iroha import IrohaCrypto
+from iroha.qry_responses_pb2 import QueryResponse
+from iroha.testing import MockIrohaGrpc
+
+
+def test_get_transaction(iroha, private_key):
+    tx = IrohaCrypto.sign_transaction(
+        iroha.transaction([iroha.command('CreateDomain', domain_id='domain', default_role='user')]),
+        private_key)
+
+    def get_transactions(query):
+        response = QueryResponse()
+        if query.payload.get_transactions.tx_hashes == [IrohaCrypto.hash(tx).hex()]:
+            response.transactions_response.transactions.extend([tx])
+        else:
+            response.error_response.error_code = 4
+        return response
+
+    net = MockIrohaGrpc()
+    net.set_query_response('GetTransactions', get_transactions)
+    assert net.get_transaction(iroha, private_key, IrohaCrypto.hash(tx)) == tx
+    decoded = net.get_transaction(iroha, private_key, IrohaCrypto.hash(tx).hex(), as_dict=True)
+    command = decoded['payload']['reduced_payload']['commands'][0]
+    assert command['create_domain']['domain_id'] == 'domain'
+    assert len(decoded['signatures']) == 1
+    assert net.get_transaction(iroha, private_key, '00' * 32) is None
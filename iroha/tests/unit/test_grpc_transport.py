@@ -0,0 +1,66 @@
+"""Test to check that IrohaGrpc works through a custom channel"""
+
+from google.protobuf.empty_pb2 import Empty
+
+from iroha import IrohaGrpc
+
+
+class _CapturingChannel(object):
+    """Python-implemented transport which answers every call with an empty response"""
+
+    def __init__(self):
+        self.calls = []
+        self.metadata = []
+
+    def unary_unary(self, method, request_serializer=None, response_deserializer=None):
+        def call(request, timeout=None, metadata=None, **kwargs):
+            self.calls.append((method, request_serializer(request)))
+            self.metadata.append(metadata)
+            return response_deserializer(b'') if response_deserializer else Empty()
+        return call
+
+    def unary_stream(self, method, request_serializer=None, response_deserializer=None):
+        def call(request, timeout=None, metadata=None, **kwargs):
+            self.calls.append((method, request_serializer(request)))
+            self.metadata.append(metadata)
+            return iter([])
+        return call
+
+
+def test_send_tx_through_custom_channel(iroha):
+    channel = _CapturingChannel()
+    net = IrohaGrpc(channel=channel)
+    tx = iroha.transaction([iroha.command('CreateDomain', domain_id='domain', default_role='user')])
+    net.send_tx(tx)
+    assert channel.calls == [('/iroha.protocol.CommandService_v1/Torii', tx.SerializeToString())]
+
+
+def test_proxy_channel_options():
+    assert IrohaGrpc._channel_options() == []
+    assert IrohaGrpc._channel_options(proxy='http://proxy:3128') == \
+        [('grpc.http_proxy', 'http://proxy:3128')]
+    assert IrohaGrpc._channel_options(proxy=False) == [('grpc.enable_http_proxy', 0)]
+    IrohaGrpc('127.0.0.1:50051', proxy='http://proxy:3128')
+
+
+def test_metadata_is_sent(iroha):
+    channel = _CapturingChannel()
+    net = IrohaGrpc(channel=channel, metadata=[('authorization', 'Bearer token')])
+    tx = iroha.transaction([iroha.command('CreateDomain', domain_id='domain', default_role='user')])
+    net.send_tx(tx)
+    net.send_tx(tx, metadata=[('x-request-id', '42')])
+    assert channel.metadata == [
+        (('authorization', 'Bearer token'),),
+        (('authorization', 'Bearer token'), ('x-request-id', '42')),
+    ]
+
+
+def test_metadata_is_sent_with_every_request(iroha):
+    channel = _CapturingChannel()
+    net = IrohaGrpc(channel=channel)
+    tx = iroha.transaction([iroha.command('CreateDomain', domain_id='domain', default_role='user')])
+    net.send_tx_blocking(tx, timeout=5, metadata=[('x-request-id', '42')])
+    # the status stream is empty, so the status is requested once more
+    assert [method.rsplit('/', 1)[-1] for method, _ in channel.calls] == \
+        ['Torii', 'StatusStream', 'Status']
+    assert channel.metadata == [(('x-request-id', '42'),)] * 3
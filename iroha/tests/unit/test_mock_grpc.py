@@ -0,0 +1,84 @@
+"""Test to check the in-memory Iroha client"""
+
+import asyncio
+
+import grpc
+
+from iroha import IrohaCrypto
+from iroha.qry_responses_pb2 import BlockQueryResponse, QueryResponse
+from iroha.testing import MockIrohaGrpc
+
+
+def test_sent_transactions_are_recorded_and_committed(iroha, private_key):
+    net = MockIrohaGrpc()
+    tx = IrohaCrypto.sign_transaction(
+        iroha.transaction([iroha.command('CreateDomain', domain_id='domain', default_role='user')]),
+        private_key)
+    net.send_tx(tx)
+    assert net.transactions == [tx]
+    statuses = [status for status, _, _ in net.tx_status_stream(tx)]
+    assert statuses[-1] == 'COMMITTED'
+
+
+def test_custom_statuses_and_unknown_transaction(iroha):
+    net = MockIrohaGrpc()
+    tx = iroha.transaction([iroha.command('CreateDomain', domain_id='domain', default_role='user')])
+    assert net.tx_status(tx)[0] == 'NOT_RECEIVED'
+    net.set_tx_statuses(tx, ['STATELESS_VALIDATION_FAILED'])
+    net.send_tx(tx)
+    assert net.tx_status(tx)[0] == 'STATELESS_VALIDATION_FAILED'
+
+
+def test_query_responses(iroha, private_key):
+    net = MockIrohaGrpc()
+    query = IrohaCrypto.sign_query(iroha.query('GetAssetInfo', asset_id='coin#domain'), private_key)
+    assert net.send_query(query).HasField('error_response')
+
+    canned = QueryResponse()
+    canned.asset_response.asset.asset_id = 'coin#domain'
+    net.set_query_response('GetAssetInfo', canned)
+    assert net.send_query(query).asset_response.asset.asset_id == 'coin#domain'
+    assert net.queries == [query, query]
+
+
+def test_blocks_stream_queue(iroha):
+    net = MockIrohaGrpc()
+    block = BlockQueryResponse()
+    block.block_response.block.block_v1.payload.height = 1
+    net.add_block(block)
+
+    async def consume():
+        queue = net.blocks_stream_queue(iroha.blocks_query())
+        items = []
+        while True:
+            item = await asyncio.wait_for(queue.get(), 5)
+            if item is None:
+                return items
+            items.append(item)
+
+    assert asyncio.run(consume()) == [block]
+
+
+def test_send_tx_blocking_waits_for_final_status(iroha):
+    net = MockIrohaGrpc()
+    tx = iroha.transaction([iroha.command('CreateDomain', domain_id='domain', default_role='user')])
+    assert net.send_tx_blocking(tx)[0] == 'COMMITTED'
+
+    other = iroha.transaction([iroha.command('CreateDomain', domain_id='other', default_role='user')])
+    net.set_tx_statuses(other, ['ENOUGH_SIGNATURES_COLLECTED', 'STATEFUL_VALIDATION_FAILED',
+                                'COMMITTED'])
+    assert net.send_tx_blocking(other)[0] == 'STATEFUL_VALIDATION_FAILED'
+
+
+def test_send_txs_blocking_reports_each_transaction(iroha):
+    net = MockIrohaGrpc()
+    committed, rejected = [
+        iroha.transaction([iroha.command('CreateDomain', domain_id=domain, default_role='user')])
+        for domain in ('first', 'second')]
+    net.set_tx_statuses(rejected, ['STATELESS_VALIDATION_SUCCESS', 'REJECTED'])
+    report = net.send_txs_blocking([committed, rejected], timeout=5)
+    assert list(report) == [IrohaCrypto.hash(committed).hex(), IrohaCrypto.hash(rejected).hex()]
+    assert [status[0] for status in report.values()] == ['COMMITTED', 'REJECTED']
+
+    net.set_rpc_error('StatusStream', grpc.StatusCode.DEADLINE_EXCEEDED)
+    assert list(net.send_txs_blocking([committed], timeout=5).values()) == [None]
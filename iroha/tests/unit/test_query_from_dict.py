@@ -0,0 +1,32 @@
+"""Test to check queries creation from plain dicts"""
+
+import pytest
+
+from iroha import SerializationError
+
+
+def test_query_with_nested_arguments(iroha):
+    query = iroha.query_from_dict({
+        'query': 'GetAccountAssets',
+        'account_id': 'alice@test',
+        'pagination_meta': {'page_size': 10, 'first_asset_id': 'coin#test'}
+    }, counter=5)
+    assert query.payload.WhichOneof('query') == 'get_account_assets'
+    assert query.payload.get_account_assets.account_id == 'alice@test'
+    assert query.payload.get_account_assets.pagination_meta.page_size == 10
+    assert query.payload.meta.query_counter == 5
+
+
+def test_query_without_arguments(iroha):
+    query = iroha.query_from_dict({'query': 'GetRoles'})
+    assert query.payload.WhichOneof('query') == 'get_roles'
+
+
+def test_unknown_query(iroha):
+    with pytest.raises(SerializationError):
+        iroha.query_from_dict({'query': 'GetEverything'})
+
+
+def test_arguments_are_validated(iroha):
+    with pytest.raises(SerializationError):
+        iroha.query_from_dict({'query': 'GetAccount', 'account': 'alice@test'})
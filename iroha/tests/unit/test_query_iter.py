@@ -0,0 +1,50 @@
+"""Test to check lazy iteration over paginated query results"""
+
+import json
+
+import pytest
+
+from iroha import SerializationError
+from iroha.qry_responses_pb2 import QueryResponse
+from iroha.testing import MockIrohaGrpc
+
+ASSETS = ['coin{}#test'.format(i) for i in range(5)]
+
+
+def account_assets(query):
+    meta = query.payload.get_account_assets.pagination_meta
+    start = ASSETS.index(meta.first_asset_id) if meta.first_asset_id else 0
+    page = ASSETS[start:start + meta.page_size]
+    response = QueryResponse()
+    for asset_id in page:
+        response.account_assets_response.account_assets.add(asset_id=asset_id, balance='1')
+    response.account_assets_response.total_number = len(ASSETS)
+    if start + meta.page_size < len(ASSETS):
+        response.account_assets_response.next_asset_id = ASSETS[start + meta.page_size]
+    return response
+
+
+def test_assets_are_fetched_page_by_page(iroha, private_key):
+    net = MockIrohaGrpc()
+    net.set_query_response('GetAccountAssets', account_assets)
+    assets = net.query_iter(iroha, private_key, 'GetAccountAssets', page_size=2,
+                            account_id='alice@test')
+    assert next(assets).asset_id == ASSETS[0]
+    assert len(net.queries) == 1
+    assert [asset.asset_id for asset in assets] == ASSETS[1:]
+    assert len(net.queries) == 3
+    assert [query.payload.meta.query_counter for query in net.queries] == [1, 2, 3]
+
+
+def test_account_detail_records(iroha, private_key):
+    net = MockIrohaGrpc()
+    response = QueryResponse()
+    response.account_detail_response.detail = json.dumps({'admin@test': {'age': '30'}})
+    net.set_query_response('GetAccountDetail', response)
+    records = list(net.query_iter(iroha, private_key, 'GetAccountDetail', account_id='alice@test'))
+    assert records == [('admin@test', 'age', '30')]
+
+
+def test_not_paginated_query(iroha, private_key):
+    with pytest.raises(SerializationError):
+        next(MockIrohaGrpc().query_iter(iroha, private_key, 'GetRoles'))
@@ -0,0 +1,21 @@
+"""Test to check server side ordering of paginated queries"""
+
+import pytest
+
+from iroha import SerializationError
+from iroha.queries_pb2 import kCreatedTime, kPosition, kAscending, kDescending
+
+
+def test_ordering_is_set(iroha):
+    query = iroha.query('GetAccountTransactions', account_id='alice@test', page_size=10,
+                        ordering=[('kCreatedTime', 'kDescending'), (kPosition, kAscending)])
+    meta = query.payload.get_account_transactions.pagination_meta
+    assert meta.page_size == 10
+    assert [(item.field, item.direction) for item in meta.ordering.sequence] == \
+        [(kCreatedTime, kDescending), (kPosition, kAscending)]
+
+
+def test_unknown_ordering_field(iroha):
+    with pytest.raises(SerializationError):
+        iroha.query('GetAccountTransactions', account_id='alice@test', page_size=10,
+                    ordering=[('kAmount', 'kAscending')])
@@ -0,0 +1,103 @@
+"""Test to check recording and replaying of grpc sessions"""
+
+import json
+import threading
+
+import grpc
+import pytest
+
+from iroha import IrohaGrpc
+from iroha.endpoint_pb2 import ToriiResponse, TxStatus
+from iroha.testing import RecordingChannel, ReplayChannel
+
+
+class PeerChannel(object):
+    """Channel standing for a peer, works with raw bytes as the real one does without serializers"""
+
+    def unary_unary(self, method, request_serializer=None, response_deserializer=None):
+        def call(request, timeout=None, **kwargs):
+            if method.endswith('/Find'):
+                raise ReplayedError()
+            return b''
+        return call
+
+    def unary_stream(self, method, request_serializer=None, response_deserializer=None):
+        def call(request, timeout=None, **kwargs):
+            for status in ('STATELESS_VALIDATION_SUCCESS', 'COMMITTED'):
+                yield ToriiResponse(tx_status=TxStatus.Value(status)).SerializeToString()
+        return call
+
+
+class HangingCall(object):
+    """Server stream sending a single status and then waiting until it is cancelled"""
+
+    def __init__(self):
+        self._cancelled = threading.Event()
+        self._sent = False
+
+    def __iter__(self):
+        return self
+
+    def __next__(self):
+        if not self._sent:
+            self._sent = True
+            return ToriiResponse(tx_status=TxStatus.Value('ENOUGH_SIGNATURES_COLLECTED')) \
+                .SerializeToString()
+        self._cancelled.wait()
+        raise CancelledError()
+
+    def cancel(self):
+        self._cancelled.set()
+
+
+class CancelledError(grpc.RpcError):
+    def code(self):
+        return grpc.StatusCode.CANCELLED
+
+    def details(self):
+        return 'cancelled'
+
+
+class ReplayedError(grpc.RpcError):
+    def code(self):
+        return grpc.StatusCode.PERMISSION_DENIED
+
+    def details(self):
+        return 'denied'
+
+
+def test_recorded_session_is_replayed(iroha, tmp_path):
+    path = str(tmp_path / 'session.jsonl')
+    tx = iroha.transaction([iroha.command('CreateDomain', domain_id='domain', default_role='user')])
+    query = iroha.query('GetRoles')
+
+    net = IrohaGrpc(channel=RecordingChannel(PeerChannel(), path))
+    net.send_tx(tx)
+    recorded = list(net.tx_status_stream(tx))
+    with pytest.raises(grpc.RpcError):
+        net.send_query(query)
+
+    replay = IrohaGrpc(channel=ReplayChannel(path))
+    replay.send_tx(tx)
+    assert list(replay.tx_status_stream(tx)) == recorded
+    with pytest.raises(grpc.RpcError) as error:
+        replay.send_query(query)
+    assert error.value.code() == grpc.StatusCode.PERMISSION_DENIED
+
+
+def test_recorded_stream_can_be_cancelled(iroha, tmp_path):
+    path = str(tmp_path / 'session.jsonl')
+    peer = PeerChannel()
+    peer.unary_stream = lambda method, **kwargs: lambda request, **call_kwargs: HangingCall()
+    tx = iroha.transaction([iroha.command('CreateDomain', domain_id='domain', default_role='user')])
+
+    stream = IrohaGrpc(channel=RecordingChannel(peer, path)).tx_status_stream(tx)
+    received = [next(stream)[0]]
+    threading.Timer(0.1, stream.cancel).start()
+    received.extend(status for status, _, _ in stream)
+    assert received == ['ENOUGH_SIGNATURES_COLLECTED']
+
+    with open(path) as records_file:
+        records = [json.loads(line) for line in records_file]
+    assert len(records) == 1 and len(records[0]['responses']) == 1
+    assert 'error' not in records[0]
@@ -0,0 +1,96 @@
+"""Test to check retries of failed calls"""
+
+import asyncio
+import time
+
+import grpc
+import pytest
+
+from iroha import RetryPolicy, ConfigError, PeerUnavailable, NotFound
+from iroha.qry_responses_pb2 import QueryResponse
+from iroha.testing import MockIrohaGrpc, MockRpcError
+
+NO_DELAY = dict(initial_backoff=0, jitter=0)
+
+
+def flaky(failures, code=grpc.StatusCode.UNAVAILABLE):
+    calls = []
+
+    def handler(query):
+        calls.append(query)
+        if len(calls) <= failures:
+            raise MockRpcError(code, 'try again')
+        return QueryResponse()
+
+    return handler, calls
+
+
+def test_retries_until_success(iroha):
+    net = MockIrohaGrpc(retry_policy=RetryPolicy(max_attempts=3, **NO_DELAY))
+    handler, calls = flaky(2)
+    net.set_query_response('GetRoles', handler)
+    net.send_query(iroha.query('GetRoles'))
+    assert len(calls) == 3
+
+
+def test_attempts_are_limited_and_overridable(iroha):
+    net = MockIrohaGrpc(retry_policy=RetryPolicy(max_attempts=2, **NO_DELAY))
+    handler, calls = flaky(5)
+    net.set_query_response('GetRoles', handler)
+    with pytest.raises(PeerUnavailable):
+        net.send_query(iroha.query('GetRoles'))
+    assert len(calls) == 2
+
+    with pytest.raises(PeerUnavailable):
+        net.send_query(iroha.query('GetRoles'), retry_policy=RetryPolicy(max_attempts=1))
+    assert len(calls) == 3
+
+
+def test_other_errors_are_not_retried(iroha):
+    net = MockIrohaGrpc(retry_policy=RetryPolicy(max_attempts=3, **NO_DELAY))
+    handler, calls = flaky(1, grpc.StatusCode.NOT_FOUND)
+    net.set_query_response('GetRoles', handler)
+    with pytest.raises(NotFound):
+        net.send_query(iroha.query('GetRoles'))
+    assert len(calls) == 1
+
+
+def test_backoff():
+    policy = RetryPolicy(initial_backoff=1, max_backoff=3, multiplier=2, jitter=0)
+    assert [policy.backoff(attempt) for attempt in (1, 2, 3)] == [1, 2, 3]
+    jittered = RetryPolicy(initial_backoff=1, jitter=0.5).backoff(1)
+    assert 0.5 <= jittered <= 1.5
+    with pytest.raises(ConfigError):
+        RetryPolicy(max_attempts=0)
+
+
+class BusyPeer(MockIrohaGrpc):
+    """Torii answers slowly and always fails with a retryable error"""
+
+    def _call(self, method, request):
+        if method in ('Torii', 'ListTorii'):
+            time.sleep(0.2)
+            raise MockRpcError(grpc.StatusCode.UNAVAILABLE, 'busy')
+        return super()._call(method, request)
+
+
+def test_blocking_submission_stays_within_timeout(iroha):
+    net = BusyPeer(retry_policy=RetryPolicy(max_attempts=10, initial_backoff=0.1, jitter=0))
+    tx = iroha.transaction([iroha.command('CreateDomain', domain_id='domain', default_role='user')])
+    started = time.monotonic()
+    with pytest.raises(PeerUnavailable):
+        net.send_tx_blocking(tx, timeout=0.5)
+    assert time.monotonic() - started < 1
+
+    started = time.monotonic()
+    with pytest.raises(PeerUnavailable):
+        asyncio.run(net.async_client().send_txs_blocking([tx], timeout=0.5))
+    assert time.monotonic() - started < 1
+
+
+def test_deadline_caps_attempt_timeout():
+    policy = RetryPolicy().with_deadline(time.monotonic() + 1)
+    assert policy.attempt_timeout(10) <= 1
+    assert policy.attempt_timeout(0.5) == 0.5
+    assert not policy.has_time_for(2)
+    assert RetryPolicy().attempt_timeout(10) == 10
@@ -0,0 +1,32 @@
+"""Test to check submission of serialized transactions"""
+
+import pytest
+
+from iroha import IrohaCrypto, SerializationError
+from iroha.testing import MockIrohaGrpc
+
+
+def make_tx(iroha):
+    return iroha.transaction(
+        [iroha.command('CreateDomain', domain_id='domain', default_role='user')], created_time=1)
+
+
+def test_signed_bytes_are_sent(iroha, private_key):
+    net = MockIrohaGrpc()
+    tx = IrohaCrypto.sign_transaction(make_tx(iroha), private_key)
+    assert net.send_tx_bytes(tx.SerializeToString()) == tx
+    assert net.transactions == [tx]
+
+
+def test_invalid_bytes_are_rejected(iroha, private_key):
+    net = MockIrohaGrpc()
+    with pytest.raises(SerializationError):
+        net.send_tx_bytes(b'\xff\xff')
+    with pytest.raises(SerializationError):
+        net.send_tx_bytes(make_tx(iroha).SerializeToString())
+
+    tampered = IrohaCrypto.sign_transaction(make_tx(iroha), private_key)
+    tampered.payload.reduced_payload.quorum = 2
+    with pytest.raises(SerializationError):
+        net.send_tx_bytes(tampered.SerializeToString())
+    assert net.transactions == []
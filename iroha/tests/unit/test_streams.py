@@ -0,0 +1,89 @@
+"""Test to check closing and cancellation of server streams"""
+
+import asyncio
+import threading
+
+import grpc
+
+from iroha import IrohaRpcError, IrohaStream, StreamQueue
+from iroha.qry_responses_pb2 import BlockQueryResponse
+from iroha.testing import MockIrohaGrpc, MockRpcError
+
+
+class CancellableCall(object):
+    def __init__(self):
+        self.cancelled = False
+
+    def cancel(self):
+        self.cancelled = True
+
+
+def test_stream_is_closed_by_context_manager(iroha):
+    net = MockIrohaGrpc()
+    for height in (1, 2):
+        block = BlockQueryResponse()
+        block.block_response.block.block_v1.payload.height = height
+        net.add_block(block)
+    with net.send_blocks_stream_query(iroha.blocks_query()) as blocks:
+        assert next(blocks).block_response.block.block_v1.payload.height == 1
+    assert blocks.cancelled
+    assert list(blocks) == []
+
+
+def test_cancel_interrupts_waiting():
+    call = CancellableCall()
+
+    def items():
+        yield 1
+        # cancellation from another thread while the stream is waiting for the next item,
+        # a real grpc call fails with CANCELLED then
+        stream.cancel()
+        raise IrohaRpcError(MockRpcError(grpc.StatusCode.CANCELLED, 'cancelled'))
+
+    stream = IrohaStream(call, items())
+    assert list(stream) == [1]
+    assert call.cancelled
+
+
+def test_closing_client_cancels_streams(iroha):
+    with MockIrohaGrpc() as net:
+        blocks = net.send_blocks_stream_query(iroha.blocks_query())
+    assert blocks.cancelled
+    assert list(blocks) == []
+
+
+def test_queue_cancel_stops_pump(iroha):
+    net = MockIrohaGrpc()
+    for height in (1, 2, 3):
+        block = BlockQueryResponse()
+        block.block_response.block.block_v1.payload.height = height
+        net.add_block(block)
+
+    async def consume():
+        queue = net.blocks_stream_queue(iroha.blocks_query(), maxsize=1)
+        first = await asyncio.wait_for(queue.get(), 5)
+        # the pump is blocked on the full queue until the queue is cancelled
+        queue.cancel()
+        await asyncio.get_event_loop().run_in_executor(None, queue._thread.join, 5)
+        return first, queue
+
+    first, queue = asyncio.run(consume())
+    assert first.block_response.block.block_v1.payload.height == 1
+    assert queue.cancelled and not queue._thread.is_alive()
+
+
+def test_queue_survives_closed_loop():
+    released = threading.Event()
+    call = CancellableCall()
+
+    def items():
+        released.wait(5)
+        raise IrohaRpcError(MockRpcError(grpc.StatusCode.UNAVAILABLE, 'gone'))
+
+    loop = asyncio.new_event_loop()
+    queue = StreamQueue(IrohaStream(call, items()), loop=loop)
+    loop.close()
+    released.set()
+    queue._thread.join(5)
+    assert not queue._thread.is_alive()
+    assert queue.cancelled and call.cancelled
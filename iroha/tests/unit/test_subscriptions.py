@@ -0,0 +1,30 @@
+"""Test to check joint shutdown of subscription groups"""
+
+import pytest
+
+from iroha.subscriptions import SubscriptionGroup
+
+
+def failing_stream():
+    yield 1
+    raise RuntimeError('stream failed')
+
+
+def test_first_error_stops_the_group():
+    polled = []
+    with pytest.raises(RuntimeError):
+        with SubscriptionGroup() as group:
+            group.add_polling(lambda: 'status', polled.append, interval=0.01)
+            group.add(failing_stream(), lambda item: None)
+            group.join(timeout=5)
+    assert group.stopped
+    assert isinstance(group.error, RuntimeError)
+
+
+def test_streams_are_consumed():
+    items = []
+    with SubscriptionGroup() as group:
+        group.add(iter([1, 2, 3]), items.append)
+        assert group.join(timeout=5)
+    assert items == [1, 2, 3]
+    assert group.error is None
@@ -0,0 +1,45 @@
+"""Test to check datetime support for creation timestamps"""
+
+import datetime
+
+import pytest
+
+from iroha import Iroha, SerializationError
+
+command = Iroha.command('CreateDomain', domain_id='domain', default_role='user')
+moment = datetime.datetime(2020, 1, 2, 3, 4, 5, 678000, tzinfo=datetime.timezone.utc)
+moment_ms = 1577934245678
+
+
+def test_aware_datetime(iroha):
+    tx = iroha.transaction([command], created_time=moment)
+    assert tx.payload.reduced_payload.created_time == moment_ms
+    query = iroha.query('GetRoles', created_time=moment)
+    assert query.payload.meta.created_time == moment_ms
+
+
+def test_naive_datetime_policies():
+    naive = moment.replace(tzinfo=None)
+    query = Iroha('admin@test', naive_datetime='utc').blocks_query(created_time=naive)
+    assert query.meta.created_time == moment_ms
+    with pytest.raises(ValueError):
+        Iroha('admin@test', naive_datetime='error').transaction([command], created_time=naive)
+
+
+def test_milliseconds_are_kept(iroha):
+    tx = iroha.transaction([command], created_time=moment_ms)
+    assert tx.payload.reduced_payload.created_time == moment_ms
+
+
+def test_invalid_timestamps(iroha):
+    strict = Iroha('admin@test', naive_datetime='error')
+    with pytest.raises(SerializationError):
+        strict.query('GetRoles', created_time=moment.replace(tzinfo=None))
+    with pytest.raises(SerializationError):
+        iroha.transaction([command], created_time='yesterday')
+
+
+def test_integral_scalars_are_accepted(iroha):
+    numpy = pytest.importorskip('numpy')
+    tx = iroha.transaction([command], created_time=numpy.int64(moment_ms))
+    assert tx.payload.reduced_payload.created_time == moment_ms
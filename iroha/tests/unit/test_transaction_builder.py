@@ -0,0 +1,22 @@
+"""Test to check step by step transaction construction"""
+
+from iroha import Iroha, IrohaCrypto, TransactionBuilder
+
+
+def test_builder_matches_iroha_transaction(iroha, private_key):
+    create_domain = Iroha.command('CreateDomain', domain_id='domain', default_role='user')
+    builder = TransactionBuilder('admin@test') \
+        .append(create_domain) \
+        .command('CreateAsset', asset_name='coin', domain_id='domain', precision=2) \
+        .quorum(2) \
+        .created_time(1000)
+    expected = iroha.transaction(
+        [create_domain,
+         Iroha.command('CreateAsset', asset_name='coin', domain_id='domain', precision=2)],
+        quorum=2, created_time=1000)
+    assert builder.build() == expected
+
+    signed = builder.sign(private_key)
+    assert len(signed.signatures) == 1
+    assert signed.signatures[0].public_key == IrohaCrypto.derive_public_key(private_key).decode()
+    assert len(builder.build().signatures) == 0
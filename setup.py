@@ -22,6 +22,10 @@ setuptools.setup(
         'pysha3;python_version<"3.6"',
         'pynacl>=1.4.0'
     ],
+    extras_require={
+        # iroha.aio is built on grpc.aio
+        'aio': ['grpcio>=1.32.0', 'grpcio-tools>=1.32.0'],
+    },
     classifiers=[
         'Programming Language :: Python :: 3',
         'Operating System :: OS Independent'